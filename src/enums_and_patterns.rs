@@ -128,6 +128,7 @@ let loopback = IpAddr::V6(String::from("::1"));
 *   wouldn't be able to with a struct. Enums handle this case with ease:
 */
 
+#[derive(Debug, PartialEq)] // so we can compare addresses later in the chapter
 enum IpAddr {
     V4(u8, u8, u8, u8),
     V6(String),
@@ -405,13 +406,61 @@ fn value_in_cents2(coin: Coin) -> u8 {
 *   is being done in the code below:
 */
 
-#[derive(Debug)] // so we can inspect the state in a minute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)] // so we can inspect the state in a minute
 enum UsState {
     Alabama,
     Alaska,
-    // --snip--
+    Arizona,
+    Arkansas,
+    California,
+    Colorado,
+    Connecticut,
+    Delaware,
+    Florida,
+    Georgia,
+    Hawaii,
+    Idaho,
+    Illinois,
+    Indiana,
+    Iowa,
+    Kansas,
+    Kentucky,
+    Louisiana,
+    Maine,
+    Maryland,
+    Massachusetts,
+    Michigan,
+    Minnesota,
+    Mississippi,
+    Missouri,
+    Montana,
+    Nebraska,
+    Nevada,
+    NewHampshire,
+    NewJersey,
+    NewMexico,
+    NewYork,
+    NorthCarolina,
+    NorthDakota,
+    Ohio,
+    Oklahoma,
+    Oregon,
+    Pennsylvania,
+    RhodeIsland,
+    SouthCarolina,
+    SouthDakota,
+    Tennessee,
+    Texas,
+    Utah,
+    Vermont,
+    Virginia,
+    Washington,
+    WestVirginia,
+    Wisconsin,
+    Wyoming,
 }
 
+#[derive(Debug, Clone)]
 enum Coin {
     Penny,
     Nickel,
@@ -735,4 +784,1538 @@ if let Coin::Quarter(state) = coin {
 /*
 *   If you have a situation in which your program has logic that is too verbose to express using a 'match', remember that 'if let' is in your
 *   Rust toolbox as well.
-*/
\ No newline at end of file
+*/
+/*
+    Putting the Coin Enum to Work
+*/
+
+/*
+*   Now that we know how to 'match' on a 'Coin', let's give the type a few associated functions of its own. The first one
+*   answers a question a cashier asks all the time: what is the biggest single coin I can hand over for this amount? We walk
+*   the denominations from largest to smallest and return the first one that doesn't exceed 'amount'. An amount of '0'
+*   can't be paid with any coin, so we return 'None' in that case.
+*
+*   Because our 'Quarter' variant carries a 'UsState', we have to pick one when we build a quarter out of thin air; we
+*   just use 'UsState::Alabama' as a placeholder here.
+*/
+
+impl Coin {
+    pub fn largest_denomination(amount: u32) -> Option<Coin> {
+        match amount {
+            0 => None,
+            1..=4 => Some(Coin::Penny),
+            5..=9 => Some(Coin::Nickel),
+            10..=24 => Some(Coin::Dime),
+            _ => Some(Coin::Quarter(UsState::Alabama)),
+        }
+    }
+}
+
+fn largest_denomination_example() {
+    assert_eq!(Coin::largest_denomination(30), Some(Coin::Quarter(UsState::Alabama)));
+    assert_eq!(Coin::largest_denomination(7), Some(Coin::Nickel));
+    assert_eq!(Coin::largest_denomination(3), Some(Coin::Penny));
+    assert_eq!(Coin::largest_denomination(0), None);
+}
+
+/*
+*   Notice that the arms use range patterns like '1..=4' to match a whole span of values at once. Calling
+*   'Coin::largest_denomination(30)' gives us a quarter, '7' gives us a nickel, '3' gives us a penny, and '0' gives 'None'.
+*/
+
+/*
+    Putting the IpAddr Enum to Work
+*/
+
+/*
+*   Our 'IpAddr' enum stores a version four address as four 'u8' values. For a lot of networking math it's easier to treat
+*   those four bytes as one 'u32', so we start with a pair of helpers that convert back and forth. 'to_u32' returns an
+*   'Option' because a 'V6' address doesn't fit in a 'u32' at all:
+*/
+
+impl IpAddr {
+    fn to_u32(&self) -> Option<u32> {
+        match self {
+            IpAddr::V4(a, b, c, d) => Some(u32::from_be_bytes([*a, *b, *c, *d])),
+            IpAddr::V6(_) => None,
+        }
+    }
+
+    fn from_u32(value: u32) -> IpAddr {
+        let [a, b, c, d] = value.to_be_bytes();
+        IpAddr::V4(a, b, c, d)
+    }
+}
+
+/*
+*   With those in place, we can split a network into smaller networks. A network written as '10.0.0.0/8' has an 8-bit
+*   prefix, which leaves 24 bits for hosts. If we split it into '/10' networks, each child gets 22 host bits, and there are
+*   2^(10 - 8) = 4 children. 'subnets' returns the base address of each child, or 'None' if the address is 'V6' or the
+*   prefixes don't make sense (the new prefix must be at least the parent prefix, and neither can exceed 32):
+*/
+
+impl IpAddr {
+    pub fn subnets(&self, parent_prefix: u8, new_prefix: u8) -> Option<Vec<IpAddr>> {
+        if parent_prefix > 32 || new_prefix > 32 || new_prefix < parent_prefix {
+            return None;
+        }
+
+        let mask = if parent_prefix == 0 { 0 } else { u32::MAX << (32 - parent_prefix) };
+        let base = (self.to_u32()? & mask) as u64;
+        let step = 1u64 << (32 - new_prefix);
+        let count = 1u64 << (new_prefix - parent_prefix);
+
+        Some((0..count).map(|i| IpAddr::from_u32((base + i * step) as u32)).collect())
+    }
+}
+
+fn subnets_example() {
+    assert_eq!(
+        IpAddr::V4(10, 0, 0, 0).subnets(8, 10),
+        Some(vec![
+            IpAddr::V4(10, 0, 0, 0),
+            IpAddr::V4(10, 64, 0, 0),
+            IpAddr::V4(10, 128, 0, 0),
+            IpAddr::V4(10, 192, 0, 0),
+        ])
+    );
+    assert_eq!(IpAddr::V4(10, 0, 0, 0).subnets(10, 8), None);
+    assert_eq!(IpAddr::localhost_v6().subnets(8, 10), None);
+}
+
+/*
+*   Notice the '?' after 'self.to_u32()': inside a function that returns an 'Option', '?' returns 'None' early if the value is
+*   'None', and otherwise unwraps the 'Some'. So 'IpAddr::V4(10, 0, 0, 0).subnets(8, 10)' gives us '10.0.0.0', '10.64.0.0',
+*   '10.128.0.0', and '10.192.0.0'. Be careful with very wide splits, though: splitting a '/0' into '/32's would allocate
+*   over four billion addresses!
+*/
+
+/*
+*   Next, let's sort a purse of coins into piles by value, like the coin-sorting machine from the start of this chapter.
+*   A 'BTreeMap' from the standard library keeps its keys in sorted order, so iterating over the piles goes from pennies up
+*   to quarters. We need a way to read the value of a coin we only borrow, so we add a small 'cents' helper that matches on
+*   '&self'. And because the map owns its 'Vec<Coin>' piles while we only borrowed the purse, we 'clone' each coin into its
+*   pile; that's why 'Coin' and 'UsState' now derive 'Clone'.
+*/
+
+impl Coin {
+    fn cents(&self) -> u8 {
+        match self {
+            Coin::Penny => 1,
+            Coin::Nickel => 5,
+            Coin::Dime => 10,
+            Coin::Quarter(_) => 25,
+        }
+    }
+
+    pub fn group_by_value(coins: &[Coin]) -> std::collections::BTreeMap<u8, Vec<Coin>> {
+        let mut groups = std::collections::BTreeMap::new();
+        for coin in coins {
+            groups
+                .entry(coin.cents())
+                .or_insert_with(Vec::new)
+                .push(coin.clone());
+        }
+        groups
+    }
+}
+
+fn group_by_value_example() {
+    let purse = [Coin::Dime, Coin::Penny, Coin::Quarter(UsState::Alaska), Coin::Penny];
+    let groups = Coin::group_by_value(&purse);
+
+    assert_eq!(groups.keys().copied().collect::<Vec<_>>(), vec![1, 10, 25]);
+    assert_eq!(groups[&1], vec![Coin::Penny, Coin::Penny]);
+    assert_eq!(groups[&10], vec![Coin::Dime]);
+    assert_eq!(groups[&25], vec![Coin::Quarter(UsState::Alaska)]);
+}
+
+/*
+*   The 'entry' API looks up a key and 'or_insert_with' creates an empty pile the first time we see a value. Grouping
+*   '[Dime, Penny, Quarter(Alaska), Penny]' gives us the keys '1', '10', and '25' in that order, with both pennies in the
+*   '1' pile.
+*/
+
+/*
+*   To send an address over a network we need to turn it into bytes. A version four address is already four bytes, and for
+*   version six we send the 'String' as UTF-8 with a one-byte length in front of it. There's a catch: "::1" is three bytes
+*   long, so with its length prefix it's four bytes too, and the receiver couldn't tell the two apart! To fix that, the wire
+*   format starts with a family byte, '4' or '6', the same way our enum starts with a variant. Reading bytes back can fail in
+*   several ways, so 'from_wire' returns a 'Result' with its own error enum:
+*/
+
+#[derive(Debug, PartialEq)]
+enum ParseIpError {
+    Truncated,
+    UnknownFamily(u8),
+    InvalidUtf8,
+    InvalidAddress,
+    InvalidPrefix,
+}
+
+impl IpAddr {
+    pub fn to_wire(&self) -> Vec<u8> {
+        match self {
+            IpAddr::V4(a, b, c, d) => vec![4, *a, *b, *c, *d],
+            IpAddr::V6(address) => {
+                assert!(
+                    address.len() <= u8::MAX as usize,
+                    "a V6 address can be at most 255 bytes on the wire, got {}",
+                    address.len()
+                );
+                let mut wire = vec![6, address.len() as u8];
+                wire.extend_from_slice(address.as_bytes());
+                wire
+            }
+        }
+    }
+
+    pub fn from_wire(bytes: &[u8]) -> Result<IpAddr, ParseIpError> {
+        match bytes {
+            [4, a, b, c, d, ..] => Ok(IpAddr::V4(*a, *b, *c, *d)),
+            [4, ..] => Err(ParseIpError::Truncated),
+            [6, len, rest @ ..] => {
+                let text = rest.get(..*len as usize).ok_or(ParseIpError::Truncated)?;
+                let address = String::from_utf8(text.to_vec()).map_err(|_| ParseIpError::InvalidUtf8)?;
+                Ok(IpAddr::V6(address))
+            }
+            [family, ..] if *family != 6 => Err(ParseIpError::UnknownFamily(*family)),
+            _ => Err(ParseIpError::Truncated),
+        }
+    }
+}
+
+fn wire_example() {
+    let home = IpAddr::V4(127, 0, 0, 1);
+    assert_eq!(home.to_wire(), vec![4, 127, 0, 0, 1]);
+    assert_eq!(IpAddr::from_wire(&home.to_wire()), Ok(home));
+
+    let loopback = IpAddr::localhost_v6();
+    assert_eq!(loopback.to_wire(), vec![6, 3, 58, 58, 49]);
+    assert_eq!(IpAddr::from_wire(&loopback.to_wire()), Ok(loopback));
+
+    assert_eq!(IpAddr::from_wire(&[4, 127, 0]), Err(ParseIpError::Truncated));
+    assert_eq!(IpAddr::from_wire(&[6, 3, 58]), Err(ParseIpError::Truncated));
+    assert_eq!(IpAddr::from_wire(&[]), Err(ParseIpError::Truncated));
+    assert_eq!(IpAddr::from_wire(&[5, 1, 2]), Err(ParseIpError::UnknownFamily(5)));
+    assert_eq!(IpAddr::from_wire(&[6, 1, 0xff]), Err(ParseIpError::InvalidUtf8));
+
+    let longest = IpAddr::V6("0".repeat(255));
+    assert_eq!(longest.to_wire().len(), 257);
+    assert_eq!(IpAddr::from_wire(&longest.to_wire()), Ok(longest));
+}
+
+/*
+*   'from_wire' matches on the slice itself! A slice pattern like '[4, a, b, c, d, ..]' only matches a slice that starts
+*   with a '4' followed by at least four more bytes, and 'rest @ ..' binds whatever is left over to 'rest'. So
+*   'IpAddr::V4(127, 0, 0, 1).to_wire()' is '[4, 127, 0, 0, 1]' and 'IpAddr::V6(String::from("::1")).to_wire()' is
+*   '[6, 3, 58, 58, 49]'; both round-trip through 'from_wire', while a cut-off message like '[4, 127, 0]' gives
+*   'Err(ParseIpError::Truncated)'.
+*
+*   The length prefix is a single byte, so it can't describe more than 255 bytes of text. Real IPv6 text is never anywhere
+*   near that long, so a longer 'V6' string is a bug in whoever built it. Cutting it off would quietly send a different
+*   address than the one we were given, so 'to_wire' panics with 'assert!' instead.
+*/
+
+/*
+*   Counting a purse out loud sounds like "3 quarters, 2 dimes, 4 pennies". 'summarize' produces exactly that: it counts
+*   each kind of coin, skips the kinds we have none of, and picks the singular or plural name. Pennies are the odd one out
+*   in English, which is why each denomination carries both of its names rather than just adding an "s":
+*/
+
+impl Coin {
+    pub fn summarize(coins: &[Coin]) -> String {
+        let denominations = [
+            (25, "quarter", "quarters"),
+            (10, "dime", "dimes"),
+            (5, "nickel", "nickels"),
+            (1, "penny", "pennies"),
+        ];
+
+        let mut parts = Vec::new();
+        for (value, singular, plural) in denominations {
+            let count = coins.iter().filter(|coin| coin.cents() == value).count();
+            match count {
+                0 => (),
+                1 => parts.push(format!("1 {}", singular)),
+                _ => parts.push(format!("{} {}", count, plural)),
+            }
+        }
+
+        parts.join(", ")
+    }
+}
+
+fn summarize_example() {
+    let purse = [
+        Coin::Quarter(UsState::Alaska),
+        Coin::Penny,
+        Coin::Dime,
+        Coin::Quarter(UsState::Ohio),
+        Coin::Penny,
+        Coin::Dime,
+        Coin::Penny,
+        Coin::Quarter(UsState::Texas),
+        Coin::Penny,
+    ];
+
+    assert_eq!(Coin::summarize(&purse), "3 quarters, 2 dimes, 4 pennies");
+    assert_eq!(Coin::summarize(&[Coin::Penny]), "1 penny");
+    assert_eq!(Coin::summarize(&[Coin::Nickel, Coin::Nickel]), "2 nickels");
+    assert_eq!(Coin::summarize(&[]), "");
+}
+
+/*
+*   The 'match' on 'count' uses '0 => ()' to do nothing, just like the dice game earlier in the chapter. A purse with a
+*   single penny reads "1 penny", and an empty purse gives an empty 'String'.
+*/
+
+/*
+*   A few addresses come up so often that they deserve names. Associated functions are a good fit for that, just like the
+*   'square' constructor in the struct chapter: 'IpAddr::localhost_v4()' is clearer than 'IpAddr::V4(127, 0, 0, 1)'.
+*   Next to them we add two methods that recognize these special addresses. Any version four address starting with '127'
+*   is a loopback address, while the "any" address is all zeros:
+*/
+
+impl IpAddr {
+    pub fn localhost_v4() -> IpAddr {
+        IpAddr::V4(127, 0, 0, 1)
+    }
+
+    pub fn any_v4() -> IpAddr {
+        IpAddr::V4(0, 0, 0, 0)
+    }
+
+    pub fn localhost_v6() -> IpAddr {
+        IpAddr::V6(String::from("::1"))
+    }
+
+    pub fn is_loopback(&self) -> bool {
+        match self {
+            IpAddr::V4(a, _, _, _) => *a == 127,
+            IpAddr::V6(address) => address == "::1",
+        }
+    }
+
+    pub fn is_unspecified(&self) -> bool {
+        match self {
+            IpAddr::V4(a, b, c, d) => [*a, *b, *c, *d] == [0, 0, 0, 0],
+            IpAddr::V6(address) => address == "::",
+        }
+    }
+}
+
+fn special_addresses_example() {
+    assert_eq!(IpAddr::localhost_v4(), IpAddr::V4(127, 0, 0, 1));
+    assert_eq!(IpAddr::any_v4(), IpAddr::V4(0, 0, 0, 0));
+    assert_eq!(IpAddr::localhost_v6(), IpAddr::V6(String::from("::1")));
+
+    assert!(IpAddr::localhost_v4().is_loopback());
+    assert!(IpAddr::localhost_v6().is_loopback());
+    assert!(!IpAddr::any_v4().is_loopback());
+
+    assert!(IpAddr::any_v4().is_unspecified());
+    assert!(!IpAddr::localhost_v4().is_unspecified());
+    assert!(!IpAddr::localhost_v6().is_unspecified());
+}
+
+/*
+*   Since our 'V6' variant is just a 'String', the version six checks only recognize the short forms "::1" and "::". A
+*   real implementation would parse the address first, which is what the standard library's 'Ipv6Addr' does.
+*/
+
+/*
+*   Because 'to_u32' turns a version four address into a number, the distance between two addresses is just the
+*   difference between their numbers. 'abs_diff' gives us that difference without caring which one is bigger, and we widen
+*   the result to 'u64' so the caller doesn't have to think about overflow. If either address is 'V6', the '?' returns 'None':
+*/
+
+impl IpAddr {
+    pub fn distance_v4(&self, other: &IpAddr) -> Option<u64> {
+        Some(self.to_u32()?.abs_diff(other.to_u32()?) as u64)
+    }
+}
+
+fn distance_v4_example() {
+    let first = IpAddr::V4(192, 168, 1, 1);
+    let tenth = IpAddr::V4(192, 168, 1, 10);
+
+    assert_eq!(first.distance_v4(&tenth), Some(9));
+    assert_eq!(tenth.distance_v4(&first), Some(9));
+    assert_eq!(
+        IpAddr::V4(0, 0, 0, 0).distance_v4(&IpAddr::V4(255, 255, 255, 255)),
+        Some(u32::MAX as u64)
+    );
+    assert_eq!(first.distance_v4(&IpAddr::localhost_v6()), None);
+}
+
+/*
+*   '192.168.1.1' and '192.168.1.10' are 9 addresses apart, in either order.
+*/
+
+/*
+*   Going the other way, 'parse_summary' reads a summary like "3 quarters, 2 dimes, 4 pennies" back into coins. Each
+*   comma-separated part has to be a count followed by a coin name, and we accept both the singular and plural names.
+*   Anything else becomes a 'ParseCoinError' that says what went wrong. As with 'largest_denomination', the quarters we
+*   create get 'UsState::Alabama' as a placeholder state, since a summary doesn't say where they're from:
+*/
+
+#[derive(Debug, PartialEq)]
+enum ParseCoinError {
+    InvalidCount(String),
+    UnknownDenomination(String),
+}
+
+impl Coin {
+    pub fn parse_summary(s: &str) -> Result<Vec<Coin>, ParseCoinError> {
+        let mut coins = Vec::new();
+
+        for part in s.split(',').map(str::trim).filter(|part| !part.is_empty()) {
+            let (count, name) = part
+                .split_once(char::is_whitespace)
+                .ok_or_else(|| ParseCoinError::InvalidCount(part.to_string()))?;
+
+            let count: usize = count
+                .parse()
+                .map_err(|_| ParseCoinError::InvalidCount(count.to_string()))?;
+
+            let coin = match name.trim().to_ascii_lowercase().as_str() {
+                "penny" | "pennies" => Coin::Penny,
+                "nickel" | "nickels" => Coin::Nickel,
+                "dime" | "dimes" => Coin::Dime,
+                "quarter" | "quarters" => Coin::Quarter(UsState::Alabama),
+                _ => return Err(ParseCoinError::UnknownDenomination(name.trim().to_string())),
+            };
+
+            coins.extend(std::iter::repeat_n(coin, count));
+        }
+
+        Ok(coins)
+    }
+}
+
+fn parse_summary_example() {
+    let purse = [
+        Coin::Penny,
+        Coin::Quarter(UsState::Alaska),
+        Coin::Dime,
+        Coin::Penny,
+        Coin::Quarter(UsState::Ohio),
+        Coin::Nickel,
+    ];
+    let summary = Coin::summarize(&purse);
+    assert_eq!(summary, "2 quarters, 1 dime, 1 nickel, 2 pennies");
+
+    let parsed = Coin::parse_summary(&summary).unwrap();
+    assert_eq!(Coin::summarize(&parsed), summary);
+    assert_eq!(parsed.len(), purse.len());
+
+    assert_eq!(Coin::parse_summary(""), Ok(vec![]));
+    assert_eq!(
+        Coin::parse_summary("2 doubloons"),
+        Err(ParseCoinError::UnknownDenomination(String::from(
+            "doubloons"
+        )))
+    );
+    assert_eq!(
+        Coin::parse_summary("two dimes"),
+        Err(ParseCoinError::InvalidCount(String::from("two")))
+    );
+}
+
+/*
+*   'std::iter::repeat_n(coin, count)' clones 'coin' 'count' times, which is one more reason 'Coin' derives 'Clone'.
+*   Feeding the output of 'summarize' into 'parse_summary' gives back a purse with the same coins, and a part like
+*   "2 doubloons" gives 'Err(ParseCoinError::UnknownDenomination(String::from("doubloons")))'.
+*/
+
+/*
+*   To draw a stack of coins we want the biggest ones first. 'stack_order' clones the purse and sorts the copy by value,
+*   highest first. 'std::cmp::Reverse' flips the ordering of whatever it wraps, so sorting by 'Reverse(coin.cents())'
+*   puts quarters at the front and pennies at the back. The sort is stable, which means coins of the same value keep the
+*   order they came in:
+*/
+
+impl Coin {
+    pub fn stack_order(coins: &[Coin]) -> Vec<Coin> {
+        let mut stack = coins.to_vec();
+        stack.sort_by_key(|coin| std::cmp::Reverse(coin.cents()));
+        stack
+    }
+}
+
+fn stack_order_example() {
+    let purse = [
+        Coin::Penny,
+        Coin::Dime,
+        Coin::Quarter(UsState::Utah),
+        Coin::Nickel,
+        Coin::Penny,
+        Coin::Quarter(UsState::Idaho),
+    ];
+    let stack = Coin::stack_order(&purse);
+
+    let values: Vec<u8> = stack.iter().map(|coin| coin.cents()).collect();
+    assert_eq!(values, vec![25, 25, 10, 5, 1, 1]);
+    assert_eq!(Coin::summarize(&stack), Coin::summarize(&purse));
+
+    // The sort is stable, so the Utah quarter stays ahead of the Idaho one.
+    assert!(matches!(stack[0], Coin::Quarter(UsState::Utah)));
+    assert!(matches!(stack[1], Coin::Quarter(UsState::Idaho)));
+}
+
+/*
+*   '[Penny, Quarter(Alaska), Dime, Nickel]' comes back as '[Quarter(Alaska), Dime, Nickel, Penny]': the same coins, just in
+*   a different order.
+*/
+
+/*
+*   A network is usually written in CIDR notation, like "192.168.1.0/24": a base address, a slash, and a prefix length
+*   saying how many leading bits every address in the network shares. 'in_subnet' checks whether 'self' shares those
+*   leading bits with 'network'. 'cidr_contains' parses the notation first and then asks 'in_subnet', reusing our
+*   'ParseIpError' for text that isn't a valid version four CIDR block:
+*/
+
+impl IpAddr {
+    pub fn in_subnet(&self, network: &IpAddr, prefix_len: u8) -> bool {
+        match (self.to_u32(), network.to_u32()) {
+            (Some(address), Some(network)) if prefix_len <= 32 => {
+                let mask = if prefix_len == 0 { 0 } else { u32::MAX << (32 - prefix_len) };
+                address & mask == network & mask
+            }
+            _ => false,
+        }
+    }
+
+    pub fn cidr_contains(cidr: &str, addr: &IpAddr) -> Result<bool, ParseIpError> {
+        let (base, prefix_len) = cidr.trim().split_once('/').ok_or(ParseIpError::InvalidPrefix)?;
+
+        let prefix_len: u8 = prefix_len.parse().map_err(|_| ParseIpError::InvalidPrefix)?;
+        if prefix_len > 32 {
+            return Err(ParseIpError::InvalidPrefix);
+        }
+
+        let octets = base
+            .split('.')
+            .map(|octet| octet.parse::<u8>())
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(|_| ParseIpError::InvalidAddress)?;
+
+        match octets[..] {
+            [a, b, c, d] => Ok(addr.in_subnet(&IpAddr::V4(a, b, c, d), prefix_len)),
+            _ => Err(ParseIpError::InvalidAddress),
+        }
+    }
+}
+
+fn cidr_contains_example() {
+    let host = IpAddr::V4(192, 168, 1, 50);
+
+    assert!(host.in_subnet(&IpAddr::V4(192, 168, 1, 0), 24));
+    assert!(!host.in_subnet(&IpAddr::V4(192, 168, 2, 0), 24));
+    assert!(!IpAddr::localhost_v6().in_subnet(&IpAddr::V4(192, 168, 1, 0), 24));
+
+    assert_eq!(IpAddr::cidr_contains("192.168.1.0/24", &host), Ok(true));
+    assert_eq!(IpAddr::cidr_contains("192.168.2.0/24", &host), Ok(false));
+    assert_eq!(IpAddr::cidr_contains("0.0.0.0/0", &host), Ok(true));
+
+    assert_eq!(
+        IpAddr::cidr_contains("192.168.1.0", &host),
+        Err(ParseIpError::InvalidPrefix)
+    );
+    assert_eq!(
+        IpAddr::cidr_contains("192.168.1.0/33", &host),
+        Err(ParseIpError::InvalidPrefix)
+    );
+    assert_eq!(
+        IpAddr::cidr_contains("192.168.1/24", &host),
+        Err(ParseIpError::InvalidAddress)
+    );
+    assert_eq!(
+        IpAddr::cidr_contains("192.168.1.256/24", &host),
+        Err(ParseIpError::InvalidAddress)
+    );
+}
+
+/*
+*   Collecting an iterator of 'Result's into a 'Result<Vec<_>, _>' stops at the first error, which is a neat trick for
+*   parsing a list where every item has to be valid. 'IpAddr::cidr_contains("192.168.1.0/24", &IpAddr::V4(192, 168, 1, 50))'
+*   is 'Ok(true)', while "192.168.1/24" gives 'Err(ParseIpError::InvalidAddress)'.
+*/
+
+/*
+*   When we want lots of purses to play with, picking coins by hand gets old. 'random_purse' asks a random number generator
+*   for 'count' coins, giving each quarter a random state as well. It uses the 'rand' crate, which is an optional dependency
+*   of this project, so the function only exists when we build with 'cargo build --features rand'. The 'cfg' attribute is
+*   what makes that happen: code under '#[cfg(feature = "rand")]' is left out entirely when the feature is off.
+*
+*   The 'rng' parameter is '&mut impl rand::Rng', meaning "a mutable borrow of any type that implements the 'Rng' trait".
+*   Taking the generator as a parameter lets callers pass a seeded generator when they want the same purse every time:
+*/
+
+#[cfg(feature = "rand")]
+impl Coin {
+    pub fn random_purse(rng: &mut impl rand::Rng, count: usize) -> Vec<Coin> {
+        (0..count)
+            .map(|_| match rng.gen_range(0..4) {
+                0 => Coin::Penny,
+                1 => Coin::Nickel,
+                2 => Coin::Dime,
+                _ => Coin::Quarter(UsState::ALL[rng.gen_range(0..UsState::ALL.len())].clone()),
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "rand")]
+fn random_purse_example() {
+    let mut rng = rand::thread_rng();
+    let purse = Coin::random_purse(&mut rng, 100);
+    let total: u32 = purse.iter().map(|coin| coin.cents() as u32).sum();
+
+    assert_eq!(purse.len(), 100);
+    assert!((100..=2500).contains(&total));
+    assert!(Coin::random_purse(&mut rng, 0).is_empty());
+}
+
+/*
+*   Every coin is worth between 1 and 25 cents, so a random purse of 10 coins always adds up to somewhere between 10 and
+*   250 cents.
+*/
+
+/*
+*   In a normal network the very first address names the network itself and the very last one is the broadcast address,
+*   so neither can be given to a host. 'first_host_v4' and 'last_host_v4' skip those two. Two prefix lengths are special:
+*   a '/31' has only two addresses and both are usable (it's meant for point-to-point links), and a '/32' is a single
+*   host. As usual, 'V6' addresses and prefixes over 32 give 'None':
+*/
+
+impl IpAddr {
+    fn host_range_v4(&self, prefix_len: u8) -> Option<(u32, u32)> {
+        if prefix_len > 32 {
+            return None;
+        }
+
+        let mask = if prefix_len == 0 { 0 } else { u32::MAX << (32 - prefix_len) };
+        let network = self.to_u32()? & mask;
+        let broadcast = network | !mask;
+
+        if prefix_len >= 31 {
+            Some((network, broadcast))
+        } else {
+            Some((network + 1, broadcast - 1))
+        }
+    }
+
+    pub fn first_host_v4(&self, prefix_len: u8) -> Option<IpAddr> {
+        let (first, _) = self.host_range_v4(prefix_len)?;
+        Some(IpAddr::from_u32(first))
+    }
+
+    pub fn last_host_v4(&self, prefix_len: u8) -> Option<IpAddr> {
+        let (_, last) = self.host_range_v4(prefix_len)?;
+        Some(IpAddr::from_u32(last))
+    }
+}
+
+fn host_range_example() {
+    let network = IpAddr::V4(192, 168, 1, 0);
+    assert_eq!(network.first_host_v4(24), Some(IpAddr::V4(192, 168, 1, 1)));
+    assert_eq!(network.last_host_v4(24), Some(IpAddr::V4(192, 168, 1, 254)));
+
+    let member = IpAddr::V4(192, 168, 1, 77);
+    assert_eq!(member.first_host_v4(24), Some(IpAddr::V4(192, 168, 1, 1)));
+
+    let point_to_point = IpAddr::V4(10, 0, 0, 0);
+    assert_eq!(point_to_point.first_host_v4(31), Some(IpAddr::V4(10, 0, 0, 0)));
+    assert_eq!(point_to_point.last_host_v4(31), Some(IpAddr::V4(10, 0, 0, 1)));
+
+    assert_eq!(network.first_host_v4(33), None);
+    assert_eq!(IpAddr::localhost_v6().last_host_v4(24), None);
+}
+
+/*
+*   For '192.168.1.0/24' the first host is '192.168.1.1' and the last is '192.168.1.254'. Any address inside the network
+*   works as 'self', because the mask throws the host bits away before we start counting.
+*/
+
+/*
+*   Back to our friend who collects state quarters! 'quarter_census' goes through a purse and counts how many quarters we
+*   have from each state, ignoring every other coin. A 'HashMap' needs to hash and compare its keys, which is why
+*   'UsState' now also derives 'PartialEq', 'Eq', and 'Hash'. An 'if let' is the perfect tool for "only care about one
+*   variant":
+*/
+
+impl Coin {
+    pub fn quarter_census(coins: &[Coin]) -> std::collections::HashMap<UsState, u32> {
+        let mut census = std::collections::HashMap::new();
+
+        for coin in coins {
+            if let Coin::Quarter(state) = coin {
+                *census.entry(state.clone()).or_insert(0) += 1;
+            }
+        }
+
+        census
+    }
+}
+
+fn quarter_census_example() {
+    let purse = [
+        Coin::Quarter(UsState::Alaska),
+        Coin::Penny,
+        Coin::Quarter(UsState::Ohio),
+        Coin::Dime,
+        Coin::Quarter(UsState::Alaska),
+        Coin::Quarter(UsState::Alaska),
+    ];
+    let census = Coin::quarter_census(&purse);
+
+    assert_eq!(census.len(), 2);
+    assert_eq!(census[&UsState::Alaska], 3);
+    assert_eq!(census[&UsState::Ohio], 1);
+    assert_eq!(census.get(&UsState::Texas), None);
+    assert!(Coin::quarter_census(&[Coin::Penny, Coin::Nickel]).is_empty());
+}
+
+/*
+*   A purse holding two Alaska quarters, one Alabama quarter, and a dime gives a census of 'Alaska => 2' and 'Alabama => 1';
+*   the dime isn't counted at all.
+*/
+
+/*
+*   The opposite of splitting a network is aggregating two networks into one. Two networks with the same prefix length can
+*   be merged only if they are the two halves of the same bigger network, like '10.0.0.0/25' and '10.0.0.128/25', which
+*   together make up '10.0.0.0/24'. 'aggregate_v4' checks exactly that: the two base addresses must be identical except for
+*   the last bit of their prefix. If so, it returns the base of the bigger network and its (one shorter) prefix length:
+*/
+
+impl IpAddr {
+    pub fn aggregate_v4(a: &IpAddr, b: &IpAddr, prefix_len: u8) -> Option<(IpAddr, u8)> {
+        if prefix_len == 0 || prefix_len > 32 {
+            return None;
+        }
+
+        let mask = u32::MAX << (32 - prefix_len);
+        let (a, b) = (a.to_u32()? & mask, b.to_u32()? & mask);
+        let last_prefix_bit = 1u32 << (32 - prefix_len);
+
+        if a ^ b == last_prefix_bit {
+            Some((IpAddr::from_u32(a & !last_prefix_bit), prefix_len - 1))
+        } else {
+            None
+        }
+    }
+}
+
+fn aggregate_v4_example() {
+    let (low, high) = (IpAddr::V4(10, 0, 0, 0), IpAddr::V4(10, 0, 0, 128));
+
+    assert_eq!(
+        IpAddr::aggregate_v4(&low, &high, 25),
+        Some((IpAddr::V4(10, 0, 0, 0), 24))
+    );
+    assert_eq!(
+        IpAddr::aggregate_v4(&high, &low, 25),
+        Some((IpAddr::V4(10, 0, 0, 0), 24))
+    );
+
+    // '10.0.0.128/25' and '10.0.1.0/25' are next to each other, but no '/24' covers both.
+    assert_eq!(
+        IpAddr::aggregate_v4(&high, &IpAddr::V4(10, 0, 1, 0), 25),
+        None
+    );
+    assert_eq!(IpAddr::aggregate_v4(&low, &low, 25), None);
+    assert_eq!(
+        IpAddr::aggregate_v4(&low, &IpAddr::localhost_v6(), 25),
+        None
+    );
+}
+
+/*
+*   The '^' operator is bitwise exclusive or, which leaves a '1' exactly where the two numbers differ. '10.0.0.0/25' and
+*   '10.0.0.128/25' aggregate to '(10.0.0.0, 24)'. '10.0.0.128/25' and '10.0.1.0/25' sit next to each other too, but they
+*   belong to different '/24' networks, so they give 'None'.
+*/
+
+/*
+*   For a small network it's handy to list every host address, for example to fill a dropdown. 'subnet_hosts_v4' reuses
+*   the range from 'first_host_v4' and 'last_host_v4' and formats each address in the usual dotted form. A '/20' network
+*   already holds 4094 hosts and every step shorter doubles that, so we refuse prefixes shorter than 20 rather than build
+*   a huge 'Vec' by accident:
+*/
+
+impl IpAddr {
+    pub fn subnet_hosts_v4(&self, prefix_len: u8) -> Option<Vec<String>> {
+        if prefix_len < 20 {
+            return None;
+        }
+
+        let (first, last) = self.host_range_v4(prefix_len)?;
+
+        Some(
+            (first..=last)
+                .map(|host| {
+                    let [a, b, c, d] = host.to_be_bytes();
+                    format!("{}.{}.{}.{}", a, b, c, d)
+                })
+                .collect(),
+        )
+    }
+}
+
+fn subnet_hosts_v4_example() {
+    let network = IpAddr::V4(192, 168, 1, 4);
+
+    assert_eq!(
+        network.subnet_hosts_v4(30),
+        Some(vec![
+            String::from("192.168.1.5"),
+            String::from("192.168.1.6")
+        ])
+    );
+    assert_eq!(
+        network.subnet_hosts_v4(32),
+        Some(vec![String::from("192.168.1.4")])
+    );
+    assert_eq!(
+        network.subnet_hosts_v4(20).map(|hosts| hosts.len()),
+        Some(4094)
+    );
+    assert_eq!(network.subnet_hosts_v4(8), None);
+    assert_eq!(IpAddr::localhost_v6().subnet_hosts_v4(30), None);
+}
+
+/*
+*   '192.168.1.0/30' has two usable hosts, so we get '["192.168.1.1", "192.168.1.2"]', while asking for a '/8' gives 'None'.
+*/
+
+/*
+*   Once an amount goes over a dollar, handing out coins only gets heavy. The 'Money' enum can hold either a 'Coin' or a
+*   bill, with the bill's value in dollars, so it shows how an enum variant can wrap another enum. 'optimize_to_money'
+*   breaks an amount of cents into the fewest pieces by always taking the biggest piece that still fits, starting with
+*   the common bills ($100, $50, $20, $10, $5, and $1; the rare $2 bill is left out) and then using coins for whatever is
+*   left. For US money, taking the biggest piece first always gives the fewest pieces:
+*/
+
+#[derive(Debug, Clone, PartialEq)]
+enum Money {
+    Coin(Coin),
+    Bill(u32),
+}
+
+impl Money {
+    pub fn optimize_to_money(cents: u32) -> Vec<Money> {
+        let mut money = Vec::new();
+        let mut remaining = cents;
+
+        for dollars in [100, 50, 20, 10, 5, 1] {
+            while remaining >= dollars * 100 {
+                money.push(Money::Bill(dollars));
+                remaining -= dollars * 100;
+            }
+        }
+
+        while let Some(coin) = Coin::largest_denomination(remaining) {
+            remaining -= coin.cents() as u32;
+            money.push(Money::Coin(coin));
+        }
+
+        money
+    }
+}
+
+fn optimize_to_money_example() {
+    assert_eq!(
+        Money::optimize_to_money(236),
+        vec![
+            Money::Bill(1),
+            Money::Bill(1),
+            Money::Coin(Coin::Quarter(UsState::Alabama)),
+            Money::Coin(Coin::Dime),
+            Money::Coin(Coin::Penny),
+        ]
+    );
+    assert_eq!(
+        Money::optimize_to_money(18_500),
+        vec![
+            Money::Bill(100),
+            Money::Bill(50),
+            Money::Bill(20),
+            Money::Bill(10),
+            Money::Bill(5)
+        ]
+    );
+    assert_eq!(Money::optimize_to_money(0), vec![]);
+}
+
+/*
+*   The coins come from 'Coin::largest_denomination', which we wrote at the start of this section, and 'while let' keeps
+*   looping for as long as it returns 'Some'. 236 cents becomes two $1 bills, a quarter, a dime, and a penny.
+*/
+
+/*
+*   Back in "Patterns That Bind to Values", 'value_in_cents3' was a free function that took a 'Coin'. Since it only ever
+*   works on coins, it reads better as a method, so we move it into an 'impl Coin' block. It takes '&self' so we can ask a
+*   coin its value without giving the coin away, and it still calls out the state of every quarter it sees, just like the
+*   original did. The actual numbers come from our quiet 'cents' helper, which the other methods in this section keep
+*   using so that sorting or counting a purse doesn't print a line for every quarter in it:
+*/
+
+impl Coin {
+    pub fn value_in_cents(&self) -> u8 {
+        if let Coin::Quarter(state) = self {
+            println!("State quarter from {:?}", state);
+        }
+        self.cents()
+    }
+}
+
+fn value_in_cents_example() {
+    assert_eq!(Coin::Penny.value_in_cents(), 1);
+    assert_eq!(Coin::Nickel.value_in_cents(), 5);
+    assert_eq!(Coin::Dime.value_in_cents(), 10);
+    assert_eq!(Coin::Quarter(UsState::Alaska).value_in_cents(), 25);
+}
+
+/*
+*   'Coin::Penny.value_in_cents()' is '1', 'Coin::Nickel' gives '5', 'Coin::Dime' gives '10', and
+*   'Coin::Quarter(UsState::Alaska).value_in_cents()' prints "State quarter from Alaska" and gives '25'.
+*/
+
+/*
+*   When counting coins it helps to be able to loop over every kind of coin. 'Coin::all' returns the penny, the nickel,
+*   and the dime in an array. Quarters are left out on purpose: a quarter always carries a 'UsState', so there isn't
+*   just one quarter to return, there are fifty. Because the array has a fixed length we can say so in the return type:
+*/
+
+impl Coin {
+    pub fn all() -> [Coin; 3] {
+        [Coin::Penny, Coin::Nickel, Coin::Dime]
+    }
+}
+
+fn all_example() {
+    let coins = Coin::all();
+    assert_eq!(coins.len(), 3);
+    assert!(!coins.iter().any(|coin| matches!(coin, Coin::Quarter(_))));
+
+    let total: u32 = coins.iter().map(|coin| coin.value_in_cents() as u32).sum();
+    assert_eq!(total, 16);
+}
+
+/*
+*   Adding up 'value_in_cents' over 'Coin::all()' gives '1 + 5 + 10', which is '16'.
+*/
+
+/*
+*   A cash register needs the total value of a handful of coins. 'value_in_cents' returns a 'u8', which is plenty for a
+*   single coin but would overflow after just eleven quarters, so 'sum_coins' converts each value to a 'u32' before adding
+*   them up. That's enough for well over a hundred million quarters:
+*/
+
+fn sum_coins(coins: &[Coin]) -> u32 {
+    coins.iter().map(|coin| coin.value_in_cents() as u32).sum()
+}
+
+fn sum_coins_example() {
+    let coins = [
+        Coin::Quarter(UsState::Alaska),
+        Coin::Dime,
+        Coin::Penny,
+        Coin::Penny,
+    ];
+    assert_eq!(sum_coins(&coins), 37);
+    assert_eq!(sum_coins(&[]), 0);
+
+    // twelve quarters would already overflow a 'u8'
+    let quarters = vec![Coin::Quarter(UsState::Ohio); 12];
+    assert_eq!(sum_coins(&quarters), 300);
+}
+
+/*
+*   'sum_coins(&[Coin::Quarter(UsState::Alaska), Coin::Dime, Coin::Penny, Coin::Penny])' gives '37', and because it
+*   uses 'value_in_cents' it calls out the Alaska quarter along the way.
+*/
+
+/*
+*   The reverse question is what coins to hand back for a given amount. 'make_change' works just like the coin part of
+*   'Money::optimize_to_money': keep taking the biggest coin that still fits until nothing is left. This "greedy" approach
+*   doesn't give the fewest coins for every possible coin set (with coins worth 1, 3, and 4 cents it pays 6 cents as
+*   4 + 1 + 1 instead of 3 + 3), but for the 1, 5, 10, and 25 cent US coins it always does. The quarters use the same
+*   'UsState::Alabama' placeholder as 'largest_denomination':
+*/
+
+fn make_change(cents: u32) -> Vec<Coin> {
+    let mut coins = Vec::new();
+    let mut remaining = cents;
+
+    while let Some(coin) = Coin::largest_denomination(remaining) {
+        remaining -= coin.cents() as u32;
+        coins.push(coin);
+    }
+
+    coins
+}
+
+fn make_change_example() {
+    let change = make_change(99);
+    assert_eq!(change.len(), 9);
+    assert_eq!(
+        change,
+        vec![
+            Coin::Quarter(UsState::Alabama),
+            Coin::Quarter(UsState::Alabama),
+            Coin::Quarter(UsState::Alabama),
+            Coin::Dime,
+            Coin::Dime,
+            Coin::Penny,
+            Coin::Penny,
+            Coin::Penny,
+            Coin::Penny,
+        ]
+    );
+    assert_eq!(sum_coins(&change), 99);
+
+    assert!(make_change(0).is_empty());
+}
+
+/*
+*   'make_change(99)' gives nine coins: three quarters, two dimes, and four pennies. 'make_change(0)' gives an empty 'Vec'.
+*/
+
+/*
+*   Prices usually come to us as text like "$1.36", not as a number of cents. 'coins_for_amount_string' turns that text
+*   into cents and hands them to 'make_change'. The dollar sign is optional, and so are the cents, but there can be at most
+*   two digits after the point because there's no coin smaller than a cent. We check that every character is an ASCII
+*   digit ourselves, since 'parse' would happily accept a sign like "+1". Anything we can't read becomes a 'ChangeError'
+*   holding the original text, so the caller can show the user what was wrong:
+*/
+
+#[derive(Debug, PartialEq)]
+enum ChangeError {
+    InvalidAmount(String),
+    InsufficientFunds { balance: u32, requested: u32 },
+}
+
+fn coins_for_amount_string(s: &str) -> Result<Vec<Coin>, ChangeError> {
+    let invalid = || ChangeError::InvalidAmount(s.to_string());
+
+    let amount = s.trim();
+    let amount = amount.strip_prefix('$').unwrap_or(amount);
+    let (dollars, cents) = amount.split_once('.').unwrap_or((amount, ""));
+
+    let is_number = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+    if (dollars.is_empty() && cents.is_empty())
+        || cents.len() > 2
+        || !is_number(dollars)
+        || !is_number(cents)
+    {
+        return Err(invalid());
+    }
+
+    let dollars: u32 = if dollars.is_empty() {
+        0
+    } else {
+        dollars.parse().map_err(|_| invalid())?
+    };
+    let cents: u32 = match cents.len() {
+        0 => 0,
+        1 => cents.parse::<u32>().map_err(|_| invalid())? * 10,
+        _ => cents.parse().map_err(|_| invalid())?,
+    };
+
+    let total = dollars
+        .checked_mul(100)
+        .and_then(|dollars| dollars.checked_add(cents))
+        .ok_or_else(invalid)?;
+
+    Ok(make_change(total))
+}
+
+fn coins_for_amount_string_example() {
+    let total = |coins: Vec<Coin>| coins.iter().map(|coin| coin.cents() as u32).sum::<u32>();
+
+    assert_eq!(coins_for_amount_string("$1.36").map(total), Ok(136));
+    assert_eq!(coins_for_amount_string("1.36").map(total), Ok(136));
+    assert_eq!(coins_for_amount_string("$.5").map(total), Ok(50));
+    assert_eq!(coins_for_amount_string("$2").map(total), Ok(200));
+
+    assert_eq!(
+        coins_for_amount_string("1.999").map(total),
+        Err(ChangeError::InvalidAmount(String::from("1.999")))
+    );
+    assert!(coins_for_amount_string("abc").is_err());
+    assert!(coins_for_amount_string("$").is_err());
+    assert!(coins_for_amount_string("+1.00").is_err());
+}
+
+/*
+*   A lone "." or "$" has no digits at all, so it's rejected too, and so is an amount too big to count in a 'u32' of cents.
+*   '"$.5"' means fifty cents, just like on a price tag.
+*/
+
+/*
+*   A vending machine has to keep track of the money inside it. 'Till' holds a running balance in cents: 'accept' adds
+*   the value of the coins a customer drops in, and 'dispense' pays out an amount as coins using 'make_change'. A till
+*   can't hand out money it doesn't have, so asking for more than the balance gives 'ChangeError::InsufficientFunds'
+*   and leaves the balance as it was. This is the second variant of 'ChangeError', and it carries both numbers so the
+*   caller can say exactly how short the till is:
+*/
+
+#[derive(Debug, Default)]
+struct Till {
+    balance: u32,
+}
+
+impl Till {
+    pub fn accept(&mut self, coins: &[Coin]) {
+        let value: u32 = coins.iter().map(|coin| coin.cents() as u32).sum();
+        self.balance = self.balance.saturating_add(value);
+    }
+
+    pub fn dispense(&mut self, amount: u32) -> Result<Vec<Coin>, ChangeError> {
+        if amount > self.balance {
+            return Err(ChangeError::InsufficientFunds {
+                balance: self.balance,
+                requested: amount,
+            });
+        }
+
+        self.balance -= amount;
+        Ok(make_change(amount))
+    }
+
+    pub fn balance(&self) -> u32 {
+        self.balance
+    }
+}
+
+fn till_example() {
+    let mut till = Till::default();
+    till.accept(&[
+        Coin::Quarter(UsState::Maine),
+        Coin::Quarter(UsState::Iowa),
+        Coin::Dime,
+    ]);
+    assert_eq!(till.balance(), 60);
+
+    let change = till.dispense(35).unwrap();
+    assert_eq!(change, vec![Coin::Quarter(UsState::Alabama), Coin::Dime]);
+    assert_eq!(till.balance(), 25);
+
+    assert_eq!(
+        till.dispense(30),
+        Err(ChangeError::InsufficientFunds {
+            balance: 25,
+            requested: 30,
+        })
+    );
+    assert_eq!(till.balance(), 25);
+}
+
+/*
+*   To sort a 'Vec<Coin>' from penny to quarter with a plain 'sort', 'Coin' has to implement 'Ord'. We can't just derive
+*   it: a derived ordering would also compare the 'UsState' inside each quarter, and we only care about face value. So we
+*   write the implementations ourselves and compare 'cents'. We use 'cents' rather than 'value_in_cents' here, since a
+*   sort compares the same coin many times and we don't want it printing. 'Ord' also needs 'PartialEq' and 'Eq', and all
+*   of them must agree with each other, so equality is by face value too. That means two quarters from different states
+*   compare equal under this ordering, even though they aren't the same coin to a collector:
+*/
+
+impl PartialEq for Coin {
+    fn eq(&self, other: &Self) -> bool {
+        self.cents() == other.cents()
+    }
+}
+
+impl Eq for Coin {}
+
+impl Ord for Coin {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.cents().cmp(&other.cents())
+    }
+}
+
+impl PartialOrd for Coin {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn coin_ordering_example() {
+    let mut coins = vec![
+        Coin::Quarter(UsState::Alaska),
+        Coin::Penny,
+        Coin::Dime,
+        Coin::Nickel,
+        Coin::Penny,
+    ];
+    coins.sort();
+    assert_eq!(
+        coins,
+        vec![
+            Coin::Penny,
+            Coin::Penny,
+            Coin::Nickel,
+            Coin::Dime,
+            Coin::Quarter(UsState::Alaska),
+        ]
+    );
+    // the state is still there after sorting, equality just doesn't look at it
+    assert!(matches!(coins[4], Coin::Quarter(UsState::Alaska)));
+
+    assert_eq!(
+        Coin::Quarter(UsState::Alabama),
+        Coin::Quarter(UsState::Alaska)
+    );
+    assert!(Coin::Dime < Coin::Quarter(UsState::Texas));
+    assert_eq!(coins.iter().max(), Some(&Coin::Quarter(UsState::Utah)));
+}
+
+/*
+*   Sorting '[Quarter(Alaska), Penny, Dime, Nickel, Penny]' gives '[Penny, Penny, Nickel, Dime, Quarter(Alaska)]', and
+*   'Coin::Quarter(UsState::Alabama) == Coin::Quarter(UsState::Alaska)' is 'true'.
+*/
+
+/*
+*   Our 'UsState' enum now lists all 50 states instead of stopping at Alaska with a '// --snip--'. Quarters are usually
+*   labeled with the two-letter postal abbreviation, so 'abbreviation' maps every variant to its code with one big
+*   'match'. Because the match has to be exhaustive, the compiler will tell us if we ever add a variant and forget its
+*   abbreviation. The strings are written right into the program, so they live for the whole run and we can return them
+*   as '&'static str' without allocating.
+*
+*   We also add an associated constant, 'UsState::ALL', holding every state in alphabetical order. That gives us a way to
+*   loop over the states, and 'random_purse' now picks its quarter states from it, so a random purse can hold quarters
+*   from anywhere in the country:
+*/
+
+impl UsState {
+    pub const ALL: [UsState; 50] = [
+        UsState::Alabama,
+        UsState::Alaska,
+        UsState::Arizona,
+        UsState::Arkansas,
+        UsState::California,
+        UsState::Colorado,
+        UsState::Connecticut,
+        UsState::Delaware,
+        UsState::Florida,
+        UsState::Georgia,
+        UsState::Hawaii,
+        UsState::Idaho,
+        UsState::Illinois,
+        UsState::Indiana,
+        UsState::Iowa,
+        UsState::Kansas,
+        UsState::Kentucky,
+        UsState::Louisiana,
+        UsState::Maine,
+        UsState::Maryland,
+        UsState::Massachusetts,
+        UsState::Michigan,
+        UsState::Minnesota,
+        UsState::Mississippi,
+        UsState::Missouri,
+        UsState::Montana,
+        UsState::Nebraska,
+        UsState::Nevada,
+        UsState::NewHampshire,
+        UsState::NewJersey,
+        UsState::NewMexico,
+        UsState::NewYork,
+        UsState::NorthCarolina,
+        UsState::NorthDakota,
+        UsState::Ohio,
+        UsState::Oklahoma,
+        UsState::Oregon,
+        UsState::Pennsylvania,
+        UsState::RhodeIsland,
+        UsState::SouthCarolina,
+        UsState::SouthDakota,
+        UsState::Tennessee,
+        UsState::Texas,
+        UsState::Utah,
+        UsState::Vermont,
+        UsState::Virginia,
+        UsState::Washington,
+        UsState::WestVirginia,
+        UsState::Wisconsin,
+        UsState::Wyoming,
+    ];
+
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            UsState::Alabama => "AL",
+            UsState::Alaska => "AK",
+            UsState::Arizona => "AZ",
+            UsState::Arkansas => "AR",
+            UsState::California => "CA",
+            UsState::Colorado => "CO",
+            UsState::Connecticut => "CT",
+            UsState::Delaware => "DE",
+            UsState::Florida => "FL",
+            UsState::Georgia => "GA",
+            UsState::Hawaii => "HI",
+            UsState::Idaho => "ID",
+            UsState::Illinois => "IL",
+            UsState::Indiana => "IN",
+            UsState::Iowa => "IA",
+            UsState::Kansas => "KS",
+            UsState::Kentucky => "KY",
+            UsState::Louisiana => "LA",
+            UsState::Maine => "ME",
+            UsState::Maryland => "MD",
+            UsState::Massachusetts => "MA",
+            UsState::Michigan => "MI",
+            UsState::Minnesota => "MN",
+            UsState::Mississippi => "MS",
+            UsState::Missouri => "MO",
+            UsState::Montana => "MT",
+            UsState::Nebraska => "NE",
+            UsState::Nevada => "NV",
+            UsState::NewHampshire => "NH",
+            UsState::NewJersey => "NJ",
+            UsState::NewMexico => "NM",
+            UsState::NewYork => "NY",
+            UsState::NorthCarolina => "NC",
+            UsState::NorthDakota => "ND",
+            UsState::Ohio => "OH",
+            UsState::Oklahoma => "OK",
+            UsState::Oregon => "OR",
+            UsState::Pennsylvania => "PA",
+            UsState::RhodeIsland => "RI",
+            UsState::SouthCarolina => "SC",
+            UsState::SouthDakota => "SD",
+            UsState::Tennessee => "TN",
+            UsState::Texas => "TX",
+            UsState::Utah => "UT",
+            UsState::Vermont => "VT",
+            UsState::Virginia => "VA",
+            UsState::Washington => "WA",
+            UsState::WestVirginia => "WV",
+            UsState::Wisconsin => "WI",
+            UsState::Wyoming => "WY",
+        }
+    }
+}
+
+fn abbreviation_example() {
+    assert_eq!(UsState::ALL.len(), 50);
+    assert_eq!(UsState::Alabama.abbreviation(), "AL");
+    assert_eq!(UsState::NewHampshire.abbreviation(), "NH");
+    assert_eq!(UsState::Wyoming.abbreviation(), "WY");
+
+    let codes: std::collections::HashSet<&str> =
+        UsState::ALL.iter().map(|state| state.abbreviation()).collect();
+    assert_eq!(codes.len(), 50);
+    assert!(codes.iter().all(|code| code.len() == 2
+        && code.chars().all(|c| c.is_ascii_uppercase())));
+
+    let states: std::collections::HashSet<&UsState> = UsState::ALL.iter().collect();
+    assert_eq!(states.len(), 50);
+}
+
+/*
+*   'UsState::California.abbreviation()' is '"CA"', and collecting the abbreviation of every state in 'UsState::ALL' into
+*   a 'HashSet' gives 50 entries, so no two states share a code.
+*/
+
+/*
+*   For a collector, the year a state joined the union is part of the story of its quarter; the state quarters were even
+*   released in that order. 'year_of_statehood' is another method that's just one big 'match', this time returning a
+*   number. A 'u16' holds any year we need. For the original thirteen states, the year is when they ratified the
+*   Constitution:
+*/
+
+impl UsState {
+    pub fn year_of_statehood(&self) -> u16 {
+        match self {
+            UsState::Alabama => 1819,
+            UsState::Alaska => 1959,
+            UsState::Arizona => 1912,
+            UsState::Arkansas => 1836,
+            UsState::California => 1850,
+            UsState::Colorado => 1876,
+            UsState::Connecticut => 1788,
+            UsState::Delaware => 1787,
+            UsState::Florida => 1845,
+            UsState::Georgia => 1788,
+            UsState::Hawaii => 1959,
+            UsState::Idaho => 1890,
+            UsState::Illinois => 1818,
+            UsState::Indiana => 1816,
+            UsState::Iowa => 1846,
+            UsState::Kansas => 1861,
+            UsState::Kentucky => 1792,
+            UsState::Louisiana => 1812,
+            UsState::Maine => 1820,
+            UsState::Maryland => 1788,
+            UsState::Massachusetts => 1788,
+            UsState::Michigan => 1837,
+            UsState::Minnesota => 1858,
+            UsState::Mississippi => 1817,
+            UsState::Missouri => 1821,
+            UsState::Montana => 1889,
+            UsState::Nebraska => 1867,
+            UsState::Nevada => 1864,
+            UsState::NewHampshire => 1788,
+            UsState::NewJersey => 1787,
+            UsState::NewMexico => 1912,
+            UsState::NewYork => 1788,
+            UsState::NorthCarolina => 1789,
+            UsState::NorthDakota => 1889,
+            UsState::Ohio => 1803,
+            UsState::Oklahoma => 1907,
+            UsState::Oregon => 1859,
+            UsState::Pennsylvania => 1787,
+            UsState::RhodeIsland => 1790,
+            UsState::SouthCarolina => 1788,
+            UsState::SouthDakota => 1889,
+            UsState::Tennessee => 1796,
+            UsState::Texas => 1845,
+            UsState::Utah => 1896,
+            UsState::Vermont => 1791,
+            UsState::Virginia => 1788,
+            UsState::Washington => 1889,
+            UsState::WestVirginia => 1863,
+            UsState::Wisconsin => 1848,
+            UsState::Wyoming => 1890,
+        }
+    }
+}
+
+fn year_of_statehood_example() {
+    assert_eq!(UsState::Delaware.year_of_statehood(), 1787);
+    assert_eq!(UsState::Hawaii.year_of_statehood(), 1959);
+    assert_eq!(UsState::Texas.year_of_statehood(), 1845);
+
+    let years = UsState::ALL.map(|state| state.year_of_statehood());
+    assert_eq!(years.iter().min(), Some(&1787));
+    assert_eq!(years.iter().max(), Some(&1959));
+    assert_eq!(years.iter().filter(|&&year| year <= 1790).count(), 13);
+}
+
+/*
+*   'UsState::Delaware.year_of_statehood()' is '1787', the first state of all, and 'UsState::Hawaii' gives '1959', the
+*   most recent.
+*/
+
+/*
+*   To read a state from a config file or the command line, and to show one to a user, we implement two standard traits.
+*   'Display' prints the state's full name, with spaces where the variant name runs words together, so
+*   'UsState::NewHampshire' prints as "New Hampshire". 'FromStr' goes the other way and is what makes 'str::parse' work.
+*   It accepts either the full name or the two-letter abbreviation, ignoring upper and lower case and any surrounding
+*   whitespace, by checking the input against every state in 'UsState::ALL'. Anything else gives a 'ParseStateError'
+*   holding the input we couldn't recognize, so the caller can say exactly what was wrong:
+*/
+
+#[derive(Debug, PartialEq)]
+enum ParseStateError {
+    UnknownState(String),
+}
+
+impl UsState {
+    fn name(&self) -> &'static str {
+        match self {
+            UsState::Alabama => "Alabama",
+            UsState::Alaska => "Alaska",
+            UsState::Arizona => "Arizona",
+            UsState::Arkansas => "Arkansas",
+            UsState::California => "California",
+            UsState::Colorado => "Colorado",
+            UsState::Connecticut => "Connecticut",
+            UsState::Delaware => "Delaware",
+            UsState::Florida => "Florida",
+            UsState::Georgia => "Georgia",
+            UsState::Hawaii => "Hawaii",
+            UsState::Idaho => "Idaho",
+            UsState::Illinois => "Illinois",
+            UsState::Indiana => "Indiana",
+            UsState::Iowa => "Iowa",
+            UsState::Kansas => "Kansas",
+            UsState::Kentucky => "Kentucky",
+            UsState::Louisiana => "Louisiana",
+            UsState::Maine => "Maine",
+            UsState::Maryland => "Maryland",
+            UsState::Massachusetts => "Massachusetts",
+            UsState::Michigan => "Michigan",
+            UsState::Minnesota => "Minnesota",
+            UsState::Mississippi => "Mississippi",
+            UsState::Missouri => "Missouri",
+            UsState::Montana => "Montana",
+            UsState::Nebraska => "Nebraska",
+            UsState::Nevada => "Nevada",
+            UsState::NewHampshire => "New Hampshire",
+            UsState::NewJersey => "New Jersey",
+            UsState::NewMexico => "New Mexico",
+            UsState::NewYork => "New York",
+            UsState::NorthCarolina => "North Carolina",
+            UsState::NorthDakota => "North Dakota",
+            UsState::Ohio => "Ohio",
+            UsState::Oklahoma => "Oklahoma",
+            UsState::Oregon => "Oregon",
+            UsState::Pennsylvania => "Pennsylvania",
+            UsState::RhodeIsland => "Rhode Island",
+            UsState::SouthCarolina => "South Carolina",
+            UsState::SouthDakota => "South Dakota",
+            UsState::Tennessee => "Tennessee",
+            UsState::Texas => "Texas",
+            UsState::Utah => "Utah",
+            UsState::Vermont => "Vermont",
+            UsState::Virginia => "Virginia",
+            UsState::Washington => "Washington",
+            UsState::WestVirginia => "West Virginia",
+            UsState::Wisconsin => "Wisconsin",
+            UsState::Wyoming => "Wyoming",
+        }
+    }
+}
+
+impl std::fmt::Display for UsState {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl std::str::FromStr for UsState {
+    type Err = ParseStateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input = s.trim();
+
+        UsState::ALL
+            .iter()
+            .find(|state| {
+                state.name().eq_ignore_ascii_case(input)
+                    || state.abbreviation().eq_ignore_ascii_case(input)
+            })
+            .cloned()
+            .ok_or_else(|| ParseStateError::UnknownState(input.to_string()))
+    }
+}
+
+fn parse_state_example() {
+    for state in UsState::ALL {
+        assert_eq!(state.to_string().parse::<UsState>(), Ok(state.clone()));
+        assert_eq!(state.abbreviation().parse::<UsState>(), Ok(state));
+    }
+
+    assert_eq!(UsState::NewHampshire.to_string(), "New Hampshire");
+    assert_eq!("california".parse::<UsState>(), Ok(UsState::California));
+    assert_eq!("  ca ".parse::<UsState>(), Ok(UsState::California));
+    assert_eq!(
+        "Atlantis".parse::<UsState>(),
+        Err(ParseStateError::UnknownState("Atlantis".to_string()))
+    );
+    assert_eq!(
+        "".parse::<UsState>(),
+        Err(ParseStateError::UnknownState(String::new()))
+    );
+}
+
+/*
+*   '"california".parse::<UsState>()' and '"CA".parse::<UsState>()' both give 'Ok(UsState::California)', while
+*   '"Atlantis".parse::<UsState>()' gives 'Err(ParseStateError::UnknownState("Atlantis".to_string()))'. For every state in
+*   'UsState::ALL', 'state.to_string().parse::<UsState>()' gives back 'Ok(state)', so the two traits round-trip.
+*/
@@ -89,9 +89,10 @@ fn area2(rect: (u32, u32)) -> u32 {
 *   a struct with a name for the whole as well as names for the parts, as shown below.
 */
 
-struct Rectangle {
-    width: u32,
-    height: u32,
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(crate) struct Rectangle {
+    pub(crate) width: u32,
+    pub(crate) height: u32,
 }
 
 fn main2() {
@@ -114,6 +115,10 @@ fn area3(rect: &Rectangle) -> u32 {
 *   Here we've defined a struct and named it 'Rectangle'. Inside the curly brackets, we
 *   defined the fields as 'width' and 'height', both of which have type 'u32'. Then, in 'main',
 *   we created a particular instance of 'Rectangle' that has a width of '30' and a height of '50'.
+*   (Ignore the '#[derive(...)]' line for now; we'll see what it's for in the next section.)
+*
+*   This is the one 'Rectangle' definition for the rest of this file. Every example below, from the 'Debug'
+*   printing to the methods, uses this same type.
 *
 *   Our 'area' function is now defined with one parameter, which we've named 'rect', whose type
 *   is an immutable borrow of a struct 'Rectangle' instance. As mentioned in Chapter 4, we want to
@@ -139,13 +144,8 @@ fn area3(rect: &Rectangle) -> u32 {
 *   macro as we have used in previous chapters. This won't work, however.
 */
 
-struct Rectangle2 {
-    width: u32,
-    height: u32,
-}
-
 fn main3() {
-    let rect1 = Rectangle2 {
+    let rect1 = Rectangle {
         width: 30,
         height: 50,
     };
@@ -154,7 +154,7 @@ fn main3() {
 }
 
 /* When we compile this code, we get an error with the core message: */
-/* error:[E0277]: 'Rectangle2' doesn't implement 'std::fmt::Display' */
+/* error:[E0277]: 'Rectangle' doesn't implement 'std::fmt::Display' */
 
 /*
 *   The 'println!' macro can do many kinds of formatting, and by default, the curly brackets
@@ -169,7 +169,7 @@ fn main3() {
 *   If we continue to read the errors, there is a helpful note:
 */
 
-/* = help: the trait 'std::format::Display' is not implemented for 'Rectangle2" */
+/* = help: the trait 'std::format::Display' is not implemented for 'Rectangle" */
 /* = help: in format strings you may be able to ue '{:?}' (or {:#?} for pretty-print) instead */
 
 /*
@@ -179,21 +179,24 @@ fn main3() {
 *   our struct in a way that is useful for developers so we can see its value while we're debugging
 *   our code.
 *
-*   You'll get an error again, this time for not using #[derive(debug)] for the 'Rectangle2' struct.
+*   Without a '#[derive(Debug)]' on the struct, you'll get an error again, this time for not implementing 'Debug'.
 *
 *   Rust does include functionality to print out debugging information, but we have to explicitly opt
 *   in to make that functionality available for our struct. To do that, we add the outer attribute
-*   '#[derive(Debug)]' just before the struct definition, as shown below.
-*/
+*   '#[derive(Debug)]' just before the struct definition, which is what the line above our 'Rectangle'
+*   definition does (it derives a few other traits as well, which we'll get to later on):
 
-#[derive(Debug)]
-struct Rectangle2 {
-    width: u32,
-    height: u32,
-}
+    #[derive(Debug)]
+    struct Rectangle {
+        width: u32,
+        height: u32,
+    }
+
+*   With that in place, the program below works:
+*/
 
 fn main4() {
-    let rect1 = Rectangle2 {
+    let rect1 = Rectangle {
         width: 30,
         height: 50,
     };
@@ -236,15 +239,9 @@ NOTE: 'dbg!' prints to the STDERR not STDOUT!!
 *   as well as the value of the whole struct in 'rect1':
 */
 
-#[derive(Debug)]
-struct Rectangle3 {
-    width: u32,
-    height: u32,
-}
-
 fn main5() {
     let scale = 2;
-    let rect1 = Rectangle3 {
+    let rect1 = Rectangle {
         width: dbg!(30 * scale),
         height: 50,
     };
@@ -259,7 +256,7 @@ fn main5() {
 *   reference to 'rect1' in the next call. Here's what the output of this example looks like:
 
 [example.rs:246:16] 30 * scale = 60
-[example.rs:250:5] &rect1 = Rectangle3 {
+[example.rs:250:5] &rect1 = Rectangle {
     width: 60,
     height: 50,
 }
@@ -270,7 +267,7 @@ fn main5() {
 *   We can see the first bit of output came from src/example.rs line 246 where we're debugging
 *   the expression '30 * scale', and its resultant value is '60' (the 'Debug' formatting impemented -
 *   - for integers is to print only their value). The 'dbg!' call on line 250 of src/example.rs
-*   outputs the value of '&rect1', which is the 'Rectangle3' type. The 'dbg!' macro can be
+*   outputs the value of '&rect1', which is the 'Rectangle' type. The 'dbg!' macro can be
 *   really helpful when you're trying to figure out what your code is doing!
 *
 *   In addition to the 'Debug' trait, Rust has provided a number of traits for us to use with
@@ -308,35 +305,153 @@ fn main5() {
 *   make an 'area' method defined on the 'Rectangle' struct, as shown below:
 */
 
-#[derive(Debug)]
-struct Rectangle4 {
-    width: u32,
-    height: u32,
+impl Rectangle {
+    pub fn area(&self) -> u32 {
+        self.width * self.height
+    }
+
+    pub fn perimeter(&self) -> u32 {
+        self.width
+            .checked_add(self.height)
+            .and_then(|half| half.checked_mul(2))
+            .expect("rectangle perimeter overflows u32")
+    }
+
+    pub fn diagonal(&self) -> f64 {
+        ((self.width as f64).powi(2) + (self.height as f64).powi(2)).sqrt()
+    }
+}
+
+fn main6() {
+    let rect1 = Rectangle::new(30, 50);
+
+    println!(
+        "The area of the rectangle is {} square pixels.",
+        rect1.area()
+    );
+
+    assert_eq!(rect1.perimeter(), 160);
+}
+
+/*
+*   We slipped a second method, 'perimeter', into the same 'impl' block: it's '2 * (width + height)'. Adding two large 'u32'
+*   values can overflow, and plain '+' and '*' would panic in a debug build but silently wrap around in a release build.
+*   'checked_add' and 'checked_mul' return 'None' on overflow instead, so 'perimeter' always panics with a clear message
+*   when the perimeter doesn't fit in a 'u32' rather than ever returning a wrong number.
+*/
+
+/*
+*   The third method in that block, 'diagonal', uses the Pythagorean theorem to find the length from one corner to the
+*   opposite one. The length usually isn't a whole number, so we convert both fields to 'f64' with 'as' before squaring
+*   them with 'powi' and taking the square root:
+*/
+
+fn diagonal_example() {
+    assert_eq!(Rectangle::new(3, 4).diagonal(), 5.0);
+    assert_eq!(Rectangle::new(0, 0).diagonal(), 0.0);
 }
 
-impl Rectangle4 {
-    #[derive(default)]
-    fn default(&self) -> Self {
+/*
+*   It's also handy to have a ready-made rectangle to start from. The standard library's 'Default' trait is the usual way
+*   to say "this is the default value of my type". A default can't come from an existing instance, so 'default' takes no
+*   'self' at all; it's an associated function that lives in an 'impl Default for Rectangle' block rather than our own
+*   'impl Rectangle'. We pick the 30x50 rectangle we've been using all along:
+*/
+
+impl Default for Rectangle {
+    fn default() -> Self {
         Self {
             width: 30,
             height: 50,
         }
     }
+}
 
-    fn area(&self) -> u32 {
-        self.width * self.height
+fn default_example() {
+    let rect = Rectangle::default();
+
+    assert_eq!(rect.width, 30);
+    assert_eq!(rect.height, 50);
+}
+
+/*
+*   So far we could compare the areas of two rectangles, which are 'u32's, but not the rectangles themselves: '=='
+*   needs the 'PartialEq' trait. That's why our 'Rectangle' definition derives 'PartialEq' and 'Eq' next to 'Debug'.
+*   The derived version compares the fields one by one, so two rectangles are equal exactly when their widths and
+*   heights are equal. ('Eq' adds the promise that every value is equal to itself, which holds for 'u32' fields.)
+*/
+
+fn equality_example() {
+    assert_eq!(Rectangle::new(30, 50), Rectangle::new(30, 50));
+    assert_ne!(Rectangle::new(30, 50), Rectangle::new(50, 30));
+    assert_eq!(Rectangle::default(), Rectangle::new(30, 50));
+}
+
+/*
+*   Comparing with '<' and '>', and sorting, need the 'PartialOrd' and 'Ord' traits. Deriving them would compare the width
+*   first and then the height, which isn't what most people mean by one rectangle being "bigger" than another. So we
+*   implement them by hand: rectangles are ordered by area, and when two areas are equal we fall back to the width and then
+*   the height. The fallbacks matter because 'Ord' has to agree with the 'PartialEq' we derived: a 30x50 and a 50x30
+*   rectangle have the same area but are not equal, so one of them has to come first. Here that's 30x50, because its width
+*   is smaller, even though neither is "bigger" in the everyday sense.
+*
+*   We compare the areas as 'u64' so that two huge rectangles can't overflow. 'Ordering::then_with' only looks at the next
+*   comparison when the previous one came out 'Equal', and 'partial_cmp' simply wraps 'cmp' in 'Some':
+*/
+
+impl Ord for Rectangle {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let area = |rect: &Rectangle| rect.width as u64 * rect.height as u64;
+
+        area(self)
+            .cmp(&area(other))
+            .then_with(|| self.width.cmp(&other.width))
+            .then_with(|| self.height.cmp(&other.height))
     }
 }
 
-fn main6() {
-    let rect1 = Rectangle4 { width, height };
+impl PartialOrd for Rectangle {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
 
-    println!(
-        "The area of the rectangle is {} square pixels.",
-        rect1.area()
+fn ordering_example() {
+    let mut rects = vec![
+        Rectangle::new(60, 45),
+        Rectangle::new(50, 30),
+        Rectangle::new(10, 40),
+        Rectangle::new(30, 50),
+    ];
+    rects.sort();
+
+    assert_eq!(
+        rects,
+        vec![
+            Rectangle::new(10, 40),
+            Rectangle::new(30, 50),
+            Rectangle::new(50, 30),
+            Rectangle::new(60, 45),
+        ]
     );
 }
 
+/*
+*   To put rectangles in a 'HashSet' (to drop duplicates) or use them as 'HashMap' keys (to count them), they need to be
+*   hashable too, so 'Rectangle' also derives 'Hash'. The derived 'Hash' looks at the same fields as the derived
+*   'PartialEq', which is exactly the rule that has to hold: two rectangles that are equal must hash the same.
+*/
+
+fn hash_example() {
+    let mut seen = std::collections::HashSet::new();
+    seen.insert(Rectangle::new(30, 50));
+    seen.insert(Rectangle::new(10, 40));
+    seen.insert(Rectangle::new(30, 50));
+    seen.insert(Rectangle::new(50, 30));
+
+    assert_eq!(seen.len(), 3);
+}
+
 /*
 *   To define the function within the context of 'Rectangle', we start an 'impl' (implementation) block
 *   for 'Rectangle'. Everything within this 'impl' block will be associated with the 'Rectangle' type.
@@ -346,7 +461,7 @@ fn main6() {
 *   to call the 'area' method on our 'Rectangle' instance. The method syntax goes after an instance: we add
 *   a dot followe by the method name, parenthesis, and any arguments
 *
-*   In the signature for 'area', we use '&self' instead of 'rect: &Rectangle4'. The '&self' is
+*   In the signature for 'area', we use '&self' instead of 'rect: &Rectangle'. The '&self' is
 *   actually short for 'self: &Self'. Within an 'impl' block, the type 'Self' is an alias for the type
 *   that the 'impl' block is for. Methods must have a parameter named 'self' of type 'Self' for their
 *   first parameter, so Rust lets you abbreviate this with only the name 'self' in the first parameter spot.
@@ -369,19 +484,15 @@ fn main6() {
 *   Note that we can choose to give a method the same name as one of the struct's fields. For example,
 *   we can define a method on 'Rectangle' that is also named 'width':
 */
-struct Rectangle5 {
-    width: u32,
-    height: u32,
-}
 
-impl Rectangle5 {
-    fn width(&self) -> bool {
+impl Rectangle {
+    pub fn width(&self) -> bool {
         self.width > 0
     }
 }
 
 fn main7() {
-    let rect1 = Rectangle5 {
+    let rect1 = Rectangle {
         width: 30,
         height: 50,
     };
@@ -416,23 +527,18 @@ fn main7() {
 *   That is, once we've defined the 'can_hold' method, we want to be able to write the program show below:
 */
 
-struct Rectangle6 {
-    width: u32,
-    height: u32,
-}
-
 fn main8() {
-    let rect1 = Rectangle6 {
+    let rect1 = Rectangle {
         width: 30,
         height: 50,
     };
 
-    let rect2 = Rectangle6 {
+    let rect2 = Rectangle {
         width: 10,
         height: 40,
     };
 
-    let rect3 = Rectangle6 {
+    let rect3 = Rectangle {
         width: 60,
         height: 45,
     };
@@ -450,23 +556,33 @@ fn main8() {
 */
 
 /*
-*   We know we want to define a method, so it will be within the 'impl Rectangle6' block. The method name
-*   will be 'can_hold', and it will take an immutable borrow of another 'Rectangle6' as a parameter. We can
+*   We know we want to define a method, so it will be within the 'impl Rectangle' block. The method name
+*   will be 'can_hold', and it will take an immutable borrow of another 'Rectangle' as a parameter. We can
 *   tell what the type of the parameter will be by looking at the code that calls the method:
-*   'rect1.can_hold(&rect2)' passes in '&rect2', which is an immutable borrow to rect2, an instance of 'Rectangle6'.
+*   'rect1.can_hold(&rect2)' passes in '&rect2', which is an immutable borrow to rect2, an instance of 'Rectangle'.
 *   This makes sense because we only need to read 'rect2' (rather than write, which would mean we'd need a -
 *   - mutable borrow), and we want 'main' to retain ownership of 'rect2' so we can use it again after calling the
 *   'can_hold' method. The return value fo 'can_hold' will be a boolean, and the implementation will check whether
-*   the width and height of 'self' are greater than the width and height of the other 'Rectangle6', respectively.
-*   Let's add the new 'can_hold' method to the 'impl' block shown below:
-*/
+*   the width and height of 'self' are greater than the width and height of the other 'Rectangle', respectively.
+*   In the book, the new 'can_hold' method goes into the same 'impl' block as 'area':
 
-impl Rectangle6 {
-    fn area(&self) -> u32 {
-        self.width * self.height
+    impl Rectangle {
+        fn area(&self) -> u32 {
+            self.width * self.height
+        }
+
+        fn can_hold(&self, other: &Rectangle) -> bool {
+            self.width > other.width && self.height > other.height
+        }
     }
 
-    fn can_hold(&self, other: Rectangle6) -> bool {
+*   Our 'area' already lives in the 'impl' block from "Defining Methods", and a second 'fn area' on the same type would be
+*   a "duplicate definitions" error, so here we only add 'can_hold'. (As we'll see in "Multiple impl Blocks" below, a
+*   struct can have as many 'impl' blocks as we like.)
+*/
+
+impl Rectangle {
+    pub fn can_hold(&self, other: &Rectangle) -> bool {
         self.width > other.width && self.height > other.height
     }
 }
@@ -475,8 +591,20 @@ impl Rectangle6 {
 *   When we run this code with the 'main' function in the code above it, we'll get our desired output. Methods can take
 *   multiple parameters that we add to the signature after the 'self' parameter, and those parameters work just like
 *   parameters in functions.
+*
+*   Because 'other' is a '&Rectangle', 'can_hold' only borrows the rectangle we pass in, so 'main8' can keep using
+*   'rect2' and 'rect3' afterwards. The checks below match the output we expect from 'main8':
 */
 
+fn can_hold_example() {
+    let rect1 = Rectangle::new(30, 50);
+    let rect2 = Rectangle::new(10, 40);
+    let rect3 = Rectangle::new(60, 45);
+
+    assert!(rect1.can_hold(&rect2));
+    assert!(!rect1.can_hold(&rect3));
+}
+
 /*
     Associated Functions
 */
@@ -491,23 +619,53 @@ impl Rectangle6 {
 *   These are often called 'new', but 'new' isn't a special name and isn't built into the language. For example,
 *   we could choose to provide an associated function named 'square' that would have one dimension parameter and use that
 *   as both width and height, thus making it easier to create a square 'Rectangle' rather than having to specify
-*   the same value twice:
+*   the same value twice. Next to it we also add the plain 'new' constructor that simply fills in both fields:
 */
 
 impl Rectangle {
-    fn sqaure(size: u32) -> Self {
+    #[must_use]
+    pub fn new(width: u32, height: u32) -> Self {
+        Self { width, height }
+    }
+
+    pub fn square(size: u32) -> Self {
         Self {
             width: size,
             height: size,
         }
     }
+
+    pub fn is_square(&self) -> bool {
+        self.width == self.height
+    }
+}
+
+fn square_example() {
+    let sq = Rectangle::square(3);
+    assert_eq!(sq.area(), 9);
+    assert!(sq.is_square());
+
+    let empty = Rectangle::square(0);
+    assert_eq!(empty.area(), 0);
+    assert!(empty.is_square());
+
+    assert!(!Rectangle::new(30, 50).is_square());
 }
 
 /*
 *   The 'self' keywords in the return type and in the body of the function are aliases for the type that appears after the
 *   'impl' keyword, which in this case is 'Rectangle'.
 *
-*   To call this associated function, we use the '::' syntax with the struct name; 'let sq = Rectangle::square(3);' is an example.
+*   The '#[must_use]' attribute on 'new' makes the compiler warn us if we call 'Rectangle::new(30, 50)' and then
+*   ignore the result, since building a rectangle and throwing it away is almost certainly a mistake. We already
+*   used 'new' in 'main6' above.
+*
+*   To call this associated function, we use the '::' syntax with the struct name; 'let sq = Rectangle::square(3);' is an example,
+*   and 'square_example' above checks that it really gives a 3x3 rectangle with an area of 9. Note that 'Rectangle::square(0)'
+*   is perfectly fine: it builds a 0x0 rectangle whose area is simply '0', not an error.
+*
+*   The same 'impl' block also has an 'is_square' method, a '&self' method returning a 'bool' much like the 'width' method
+*   from earlier. Every rectangle made by 'square' is square, including the 0x0 one, since its width and height are equal.
 *   This function is namespaced by the struct: the '::' syntax is used for both associated functions and namespaces created
 *   by modules. Modules are discussed in Chapter 7.
 */
@@ -518,22 +676,20 @@ impl Rectangle {
 
 /*
 *   Each struct is allowed to have mutliple 'impl' blocks. For example, the previous code is equivalent to the code shown below, which
-*   has each method in its own 'impl' block.
-*/
+*   has each method in its own 'impl' block. (Our 'Rectangle' already has both methods, so this version stays in a comment.)
 
-impl Rectangle {
-    fn area(&self) -> u32 {
-        self.width * self.height
+    impl Rectangle {
+        fn area(&self) -> u32 {
+            self.width * self.height
+        }
     }
-}
 
-impl Rectangle {
-    fn can_hold(&self, other: &Rectangle) -> bool {
-        self.width > other.width && self.height > other.height
+    impl Rectangle {
+        fn can_hold(&self, other: &Rectangle) -> bool {
+            self.width > other.width && self.height > other.height
+        }
     }
-}
 
-/*
 *   There's no reason to separate these methods into multiple 'impl' blocks here, but this is valid syntax.
 *   We'll see a case in which mutliple 'impl' blocks are useful in Chapter 10, where we discuss generic types and
 *   traits.
@@ -546,14 +702,22 @@ impl Rectangle {
 /*
 *   Using the concepts we've discussed so far, we can now see how method calls are syntactic sugar for function
 *   calls. For example, let's say we have a rectangle struct with an 'area' method and a 'set_width' method:
-*/
 
-impl Rectangle {
-    fn area(&self) -> u32 {
-        self.width * self.height
+    impl Rectangle {
+        fn area(&self) -> u32 {
+            self.width * self.height
+        }
+
+        fn set_width(&mut self, width: u32) {
+            self.width = width;
+        }
     }
 
-    fn set_width(&mut self, width: u32) {
+*   Our 'Rectangle' already has 'area', so the only method we actually add here is 'set_width':
+*/
+
+impl Rectangle {
+    pub fn set_width(&mut self, width: u32) {
         self.width = width;
     }
 }
@@ -618,18 +782,29 @@ fn example_again() {
 *   Like discussed in Chapter 4.2 "References and Borrowing", methods must be called on structs that have the
 *   necessary permissions. As a running example, we will use these three methods that take '&self', '&mut self', and
 *   'self', respectively.
-*/
 
-impl Rectangle {
-    fn area(&self) -> u32 {
-        self.width * self.height
-    }
+    impl Rectangle {
+        fn area(&self) -> u32 {
+            self.width * self.height
+        }
 
-    fn set_width(&mut self, width: u32) {
-        self.width = width;
+        fn set_width(&mut self, width: u32) {
+            self.width = width;
+        }
+
+        fn max(self, other: Rectangle) -> Rectangle {
+            Rectangle {
+                width: self.width.max(other.width),
+                height: self.height.max(other.height),
+            }
+        }
     }
 
-    fn max(self, other: Rectangle) -> Rectangle {
+*   We already defined 'area' and 'set_width' above, so the only new method is 'max':
+*/
+
+impl Rectangle {
+    pub fn max(self, other: Rectangle) -> Rectangle {
         Rectangle {
             width: self.width.max(other.width),
             height: self.height.max(other.height),
@@ -731,15 +906,13 @@ fn another_example4() {
 /*
 *   A similar situation arises if we try to call a 'self' method on a reference. For instance, say we tried to make
 *   a method 'set_to_max' that assigns 'self' to the output of 'self.max(..)':
-*/
 
-impl Rectangle {
-    fn set_to_max(&mut self, other: Rectangle) {
-        self = self.max(other); // Missing O (own) permission
+    impl Rectangle {
+        fn set_to_max(&mut self, other: Rectangle) {
+            self = self.max(other); // Missing O (own) permission
+        }
     }
-}
 
-/*
 *   Then we can see that 'self' is missing O permissions in the operation 'self.max(..)'. Rust therefore rejects this program
 *   with the following error:
 *
@@ -757,46 +930,46 @@ impl Rectangle {
 /*
 *   You might wonder: why does it matter if we move out of '*self'? In fact, for the case of 'Rectangle', it
 *   actually is safe to move out of '*self', even though Rust doesn't let you do it. For example, if we simulate
-*   a program that calls the rejected 'set_to_max', you can see how nothing unsafe occurs:
-*/
+*   a program that calls the rejected 'set_to_max', you can see how nothing unsafe occurs (Rust still rejects this
+*   version with the same error, so it stays in a comment too):
 
-impl Rectangle {
-    fn set_to_max(&mut self, other: Rectangle) {
-        let max = self.max(other);
-        *self = max;
+    impl Rectangle {
+        fn set_to_max(&mut self, other: Rectangle) {
+            let max = self.max(other);
+            *self = max;
+        }
     }
-}
 
-fn example_main() {
-    let mut rect = Rectangle {
-        width: 0,
-        height: 1,
-    };
-    let other_rect = Rectanlge {
-        width: 1,
-        height: 0,
-    };
-    rect.set_to_max(other_rect);
-}
+    fn example_main() {
+        let mut rect = Rectangle {
+            width: 0,
+            height: 1,
+        };
+        let other_rect = Rectangle {
+            width: 1,
+            height: 0,
+        };
+        rect.set_to_max(other_rect);
+    }
 
-/*
 *   The reason it's safe to move out of '*self' is because 'Rectangle' does not own any heap data. In fact, we can actually
 *   get Rust to compile 'set_to_max" by simply adding '#[derive(Copy, Clone)] to the definition of 'Rectangle':
-*/
 
-#[derive(Copy, Clone)]
-struct Rectangle {
-    width: u32,
-    height: u32,
-}
+    #[derive(Copy, Clone)]
+    struct Rectangle {
+        width: u32,
+        height: u32,
+    }
 
-impl Rectangle {
-    fn set_to_max(&mut self, other: Rectangle) {
-        *self = self.max(other);
+    impl Rectangle {
+        fn set_to_max(&mut self, other: Rectangle) {
+            *self = self.max(other);
+        }
     }
-}
 
-/*
+*   (This copyable version is kept inside a comment so that the rest of this file can keep sharing the single
+*   'Rectangle' definition from the "Refactoring with Structs" section.)
+*
 *   Notice that unlike before, 'self.max(other)' no longer requires the O permission on '*self' or 'other'.
 *   Remember that 'self.max(other)' desugars to 'Rectangle::max(*self, other). The dereference '*self' does not require
 *   ownership over '*self' if 'Rectangle' is copyable.
@@ -807,48 +980,46 @@ impl Rectangle {
 *   that issue, APi authors must explicitly add '#[derive(Copy)]' to indicate that they expect their struct to always be 'Copy'.
 *
 *   To better understand the issue, let's run a simulation. Say we added 'name: String' to 'Rectangle'. What would
-*   happen if Rust allowed 'set_to_max' to compile?
-*/
+*   happen if Rust allowed 'set_to_max' to compile? (This is only a thought experiment, so the code stays in a comment.)
 
-struct Rectangle {
-    width: u32,
-    height: u32,
-    name: String,
-}
+    struct Rectangle {
+        width: u32,
+        height: u32,
+        name: String,
+    }
 
-impl Rectangle {
-    fn max(self, other: Self) -> Self {
-        let w = self.width.max(other.width);
-        let h = self.height.max(other.height);
-        Rectangle {
-            width: w,
-            height: h,
-            name: String::from("max"),
+    impl Rectangle {
+        fn max(self, other: Self) -> Self {
+            let w = self.width.max(other.width);
+            let h = self.height.max(other.height);
+            Rectangle {
+                width: w,
+                height: h,
+                name: String::from("max"),
+            }
+        }
+        fn set_to_max(&mut self, other: Rectangle) {
+            let max = self.max(other);
+            drop(*self); // This is usually implicit,
+                         // but added here for clarity.
+            *self = max;
         }
     }
-    fn set_to_max(&mut self, other: Rectangle) {
-        let max = self.max(other);
-        drop(*self); // This is usually implicit,
-                     // but added here for clarity.
-        *self = max;
-    }
-}
 
-fn main() {
-    let mut r1 = Rectangle {
-        width: 9,
-        height: 9,
-        name: String::from("r1"),
-    };
-    let r2 = Rectangle {
-        width: 16,
-        height: 16,
-        name: String::from("r2"),
-    };
-    r1.set_to_max(r2);
-}
+    fn main() {
+        let mut r1 = Rectangle {
+            width: 9,
+            height: 9,
+            name: String::from("r1"),
+        };
+        let r2 = Rectangle {
+            width: 16,
+            height: 16,
+            name: String::from("r2"),
+        };
+        r1.set_to_max(r2);
+    }
 
-/*
 *   In this program, we call 'set_to_max' with two rectangles 'r1' and 'r2'. 'self' is a mutable reference to
 *   'r1' and 'other' is a move of 'r2'. After calling 'self.max(other)', the 'max' method consumes ownership of
 *   both rectangles. When 'max' returns, Rust deallocates both strings "r1" and "r2" in the heap. Notice the problem:
@@ -864,6 +1035,1516 @@ fn main() {
 *   call a 'self' method on a reference like '&self' or '&mut self'. Rust is protecting you from a double-free.
 */
 
+/*
+    Extending Our Rectangle
+*/
+
+/*
+*   The book stops here with 'Rectangle', but once you have an 'impl' block it's hard to stop adding methods! The rest of
+*   this section collects extra methods on 'Rectangle' that exercise what we've learned about '&self', '&mut self', 'self',
+*   and associated functions.
+*/
+
+/*
+*   Sometimes we want to apply several changes to a rectangle in a row. Instead of writing a new 'let' for every step, we
+*   can hand out a small builder struct, 'RectTransform', whose methods take 'self' by value and return a new 'RectTransform'.
+*   That lets us chain calls together, and the terminal 'build' method turns the result back into a 'Rectangle':
+*/
+
+pub(crate) struct RectTransform {
+    width: u32,
+    height: u32,
+}
+
+impl Rectangle {
+    pub fn transform(&self) -> RectTransform {
+        RectTransform {
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl RectTransform {
+    pub fn scale(self, factor: f64) -> RectTransform {
+        RectTransform {
+            width: (self.width as f64 * factor).round() as u32,
+            height: (self.height as f64 * factor).round() as u32,
+        }
+    }
+
+    pub fn grow(self, width: u32, height: u32) -> RectTransform {
+        RectTransform {
+            width: self.width.saturating_add(width),
+            height: self.height.saturating_add(height),
+        }
+    }
+
+    pub fn rotate90(self) -> RectTransform {
+        RectTransform {
+            width: self.height,
+            height: self.width,
+        }
+    }
+
+    pub fn build(self) -> Rectangle {
+        Rectangle {
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+fn transform_example() {
+    let rect = Rectangle {
+        width: 30,
+        height: 50,
+    };
+
+    let result = rect.transform().scale(2.0).rotate90().build();
+    assert_eq!(result, Rectangle::new(100, 60));
+
+    assert_eq!(rect.transform().grow(5, 10).build(), Rectangle::new(35, 60));
+}
+
+/*
+*   Each method in the chain consumes the 'RectTransform' it was called on, which is exactly the "transform 'self' into
+*   something else" case we described in the Method Syntax section. Scaling rounds to the nearest whole pixel (the 'as u32'
+*   cast saturates, so a negative factor gives '0'), and 'grow' saturates at 'u32::MAX' instead of overflowing.
+*/
+
+/*
+*   Methods can build 'String's too. 'compare_report' describes how 'self' compares to another rectangle in a sentence
+*   we could show to a user. The percentage is the difference relative to the area of 'other', so a 30x50 rectangle
+*   compared against a 10x40 one is 275% larger. We compute the areas as 'u64' so that two very large rectangles can't
+*   overflow, and we leave the percentage off when 'other' has no area, since dividing by zero wouldn't tell us anything:
+*/
+
+impl Rectangle {
+    pub fn compare_report(&self, other: &Rectangle) -> String {
+        let mine = self.width as u64 * self.height as u64;
+        let theirs = other.width as u64 * other.height as u64;
+        let names = (
+            format!("{}x{}", self.width, self.height),
+            format!("{}x{}", other.width, other.height),
+        );
+
+        let (relation, difference) = match mine.cmp(&theirs) {
+            std::cmp::Ordering::Equal => {
+                return format!("{} is the same size as {}", names.0, names.1);
+            }
+            std::cmp::Ordering::Greater => ("larger", mine - theirs),
+            std::cmp::Ordering::Less => ("smaller", theirs - mine),
+        };
+
+        if theirs == 0 {
+            format!(
+                "{} is {} than {} by {} square pixels",
+                names.0, relation, names.1, difference
+            )
+        } else {
+            format!(
+                "{} is {} than {} by {} square pixels ({:.1}%)",
+                names.0,
+                relation,
+                names.1,
+                difference,
+                difference as f64 / theirs as f64 * 100.0
+            )
+        }
+    }
+}
+fn compare_report_example() {
+    let rect1 = Rectangle::new(30, 50);
+    let rect2 = Rectangle::new(10, 40);
+
+    assert_eq!(
+        rect1.compare_report(&rect2),
+        "30x50 is larger than 10x40 by 1100 square pixels (275.0%)"
+    );
+    assert_eq!(
+        rect2.compare_report(&rect1),
+        "10x40 is smaller than 30x50 by 1100 square pixels (73.3%)"
+    );
+    assert_eq!(
+        rect1.compare_report(&Rectangle::new(50, 30)),
+        "30x50 is the same size as 50x30"
+    );
+}
+
+/*
+*   With this, 'rect1.compare_report(&rect2)' for our 30x50 and 10x40 rectangles from earlier gives us
+*   "30x50 is larger than 10x40 by 1100 square pixels (275.0%)", flipping them around gives
+*   "10x40 is smaller than 30x50 by 1100 square pixels (73.3%)", and comparing 30x50 to 50x30 gives
+*   "30x50 is the same size as 50x30".
+*/
+
+/*
+*   Rectangles often arrive as text, like "640x480" in a config file or on the command line. The standard library's
+*   'TryFrom' trait is the usual way to express a conversion that can fail. Traits aren't covered until Chapter 10, but
+*   implementing one looks a lot like an 'impl' block: we say which trait we're implementing, pick an 'Error' type, and
+*   write 'try_from'. The actual parsing lives in 'parse_dimensions', which splits on the 'x' and parses each side:
+*/
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum ParseRectangleError {
+    MissingSeparator,
+    InvalidNumber,
+}
+
+impl Rectangle {
+    pub fn parse_dimensions(s: &str) -> Result<(u32, u32), ParseRectangleError> {
+        let (width, height) = s
+            .trim()
+            .split_once('x')
+            .ok_or(ParseRectangleError::MissingSeparator)?;
+
+        let width = width
+            .trim()
+            .parse()
+            .map_err(|_| ParseRectangleError::InvalidNumber)?;
+        let height = height
+            .trim()
+            .parse()
+            .map_err(|_| ParseRectangleError::InvalidNumber)?;
+
+        Ok((width, height))
+    }
+}
+
+impl std::convert::TryFrom<&str> for Rectangle {
+    type Error = ParseRectangleError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let (width, height) = Rectangle::parse_dimensions(s)?;
+        Ok(Rectangle { width, height })
+    }
+}
+
+fn try_from_example() {
+    assert_eq!(Rectangle::try_from("640x480"), Ok(Rectangle::new(640, 480)));
+    assert_eq!(Rectangle::try_from(" 30 x 50 "), Ok(Rectangle::new(30, 50)));
+    assert_eq!(
+        Rectangle::try_from("640480"),
+        Err(ParseRectangleError::MissingSeparator)
+    );
+    assert_eq!(
+        Rectangle::try_from("640xabc"),
+        Err(ParseRectangleError::InvalidNumber)
+    );
+}
+
+/*
+*   Now 'Rectangle::try_from("640x480")' gives us 'Ok' with a 640 by 480 rectangle, and because the error is a plain
+*   'Result', it composes with '?' in any function that returns 'Result<_, ParseRectangleError>'. Malformed input like
+*   "640480" gives 'Err(ParseRectangleError::MissingSeparator)', and "640xabc" gives
+*   'Err(ParseRectangleError::InvalidNumber)'.
+*/
+
+/*
+*   Here's a place where structs and enums work together. Say we want to log what changed when a rectangle was resized.
+*   Each kind of change is a variant of a 'RectChange' enum (enums are the topic of the next chapter), and each variant holds
+*   the old and new value. 'changes_from' compares 'self' against an 'old' rectangle and collects one entry per dimension
+*   that differs:
+*/
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum RectChange {
+    WidthChanged { from: u32, to: u32 },
+    HeightChanged { from: u32, to: u32 },
+}
+
+impl Rectangle {
+    pub fn changes_from(&self, old: &Rectangle) -> Vec<RectChange> {
+        let mut changes = Vec::new();
+
+        if self.width != old.width {
+            changes.push(RectChange::WidthChanged {
+                from: old.width,
+                to: self.width,
+            });
+        }
+
+        if self.height != old.height {
+            changes.push(RectChange::HeightChanged {
+                from: old.height,
+                to: self.height,
+            });
+        }
+
+        changes
+    }
+}
+
+fn changes_from_example() {
+    let old = Rectangle::new(30, 50);
+
+    assert_eq!(
+        Rectangle::new(40, 50).changes_from(&old),
+        vec![RectChange::WidthChanged { from: 30, to: 40 }]
+    );
+    assert_eq!(
+        Rectangle::new(30, 20).changes_from(&old),
+        vec![RectChange::HeightChanged { from: 50, to: 20 }]
+    );
+    assert_eq!(Rectangle::new(30, 50).changes_from(&old), vec![]);
+}
+
+/*
+*   If we widen a 30x50 rectangle to 40x50, the log is just '[WidthChanged { from: 30, to: 40 }]'. Comparing a rectangle
+*   to an identical one gives an empty 'Vec'.
+*/
+
+/*
+*   A layout question: if we want to show 'count' square thumbnails in a grid inside this rectangle, with 'gap' pixels
+*   between neighbouring cells, how big can each cell be? 'cell_size_for' tries every possible number of columns, works
+*   out how many rows that needs, and keeps the biggest square side that fits both ways. There's nothing to lay out for a
+*   'count' of zero, and if even one-pixel cells don't fit we return 'None':
+*/
+
+impl Rectangle {
+    pub fn cell_size_for(&self, count: u32, gap: u32) -> Option<Rectangle> {
+        if count == 0 {
+            return None;
+        }
+
+        // A cell is at least one pixel wide, so there can never be more columns than pixels.
+        let max_columns = count.min(self.width) as u64;
+        let (width, height, gap) = (self.width as u64, self.height as u64, gap as u64);
+
+        let mut best = 0;
+        for columns in 1..=max_columns {
+            let rows = (count as u64).div_ceil(columns);
+            let free_width = width.checked_sub(gap * (columns - 1));
+            let free_height = height.checked_sub(gap * (rows - 1));
+
+            if let (Some(free_width), Some(free_height)) = (free_width, free_height) {
+                best = best.max((free_width / columns).min(free_height / rows));
+            }
+        }
+
+        if best == 0 {
+            None
+        } else {
+            Some(Rectangle {
+                width: best as u32,
+                height: best as u32,
+            })
+        }
+    }
+}
+
+fn cell_size_for_example() {
+    let area = Rectangle::new(100, 100);
+
+    assert_eq!(area.cell_size_for(4, 0), Some(Rectangle::new(50, 50)));
+    assert_eq!(area.cell_size_for(4, 10), Some(Rectangle::new(45, 45)));
+    assert_eq!(area.cell_size_for(0, 0), None);
+    assert_eq!(Rectangle::new(3, 1).cell_size_for(5, 0), None);
+}
+
+/*
+*   'checked_sub' returns 'None' instead of overflowing when the gaps alone are wider than the rectangle, and the
+*   'if let' with a tuple of two 'Option's only runs when both are 'Some'. Fitting 4 items into a 100x100 rectangle with no
+*   gap gives us 50x50 cells in a 2x2 grid.
+*/
+
+/*
+*   How many whole tiles of one size fit in a rectangle if we lay them out in rows without rotating them? That's just how
+*   many fit across times how many fit down. 'packing_waste' builds on 'tile_count' to tell us how much of the area is left
+*   uncovered. A tile with a zero dimension can't cover anything, so it counts as zero tiles instead of dividing by zero.
+*   Both results are 'u64': a big enough rectangle holds more than 'u32::MAX' one-pixel tiles, so we widen before we
+*   multiply, the same way 'compare_report' computes its areas:
+*/
+
+impl Rectangle {
+    pub fn tile_count(&self, tile: &Rectangle) -> u64 {
+        if tile.width == 0 || tile.height == 0 {
+            return 0;
+        }
+
+        (self.width / tile.width) as u64 * (self.height / tile.height) as u64
+    }
+
+    pub fn packing_waste(&self, tile: &Rectangle) -> u64 {
+        let area = self.width as u64 * self.height as u64;
+        let tile_area = tile.width as u64 * tile.height as u64;
+        area - self.tile_count(tile) * tile_area
+    }
+}
+
+fn tile_count_example() {
+    let container = Rectangle::new(35, 35);
+    let tile = Rectangle::new(10, 10);
+
+    assert_eq!(container.tile_count(&tile), 9);
+    assert_eq!(container.packing_waste(&tile), 325);
+    assert_eq!(container.tile_count(&Rectangle::new(0, 10)), 0);
+
+    // Both of these would overflow a 'u32'.
+    let huge = Rectangle::new(100_000, 100_000);
+    assert_eq!(huge.tile_count(&Rectangle::new(1, 1)), 10_000_000_000);
+    assert_eq!(huge.packing_waste(&Rectangle::new(70_000, 70_000)), 5_100_000_000);
+}
+
+/*
+*   A 35x35 rectangle holds 3 tiles of 10x10 across and 3 down, so 9 tiles cover 900 of its 1225 square pixels, leaving
+*   325 wasted.
+*/
+
+/*
+*   When a video doesn't have the same shape as the screen, players scale it as large as possible without cropping and
+*   fill the rest with black bars. 'letterbox_bars' works out how much empty space that leaves, returning the total
+*   horizontal and vertical space as a tuple. We compare the two aspect ratios by cross-multiplying instead of dividing, which
+*   keeps everything in whole numbers (the fitted size is rounded down to a whole pixel):
+*/
+
+impl Rectangle {
+    pub fn letterbox_bars(&self, content: &Rectangle) -> (u32, u32) {
+        if content.width == 0 || content.height == 0 {
+            return (self.width, self.height);
+        }
+
+        let (width, height) = (self.width as u64, self.height as u64);
+        let (content_width, content_height) = (content.width as u64, content.height as u64);
+
+        let (fitted_width, fitted_height) = if content_width * height >= content_height * width {
+            // The content is relatively wider than we are, so it fills our width.
+            (width, content_height * width / content_width)
+        } else {
+            (content_width * height / content_height, height)
+        };
+
+        ((width - fitted_width) as u32, (height - fitted_height) as u32)
+    }
+}
+
+fn letterbox_bars_example() {
+    let screen = Rectangle::new(4, 3);
+    assert_eq!(screen.letterbox_bars(&Rectangle::new(16, 9)), (0, 1));
+
+    let widescreen = Rectangle::new(1920, 1080);
+    assert_eq!(widescreen.letterbox_bars(&Rectangle::new(640, 480)), (480, 0));
+    assert_eq!(widescreen.letterbox_bars(&Rectangle::new(0, 480)), (1920, 1080));
+}
+
+/*
+*   Fitting 16x9 content into a 4x3 container scales it down to 4x2, so there's no horizontal space left over and 1 pixel
+*   of vertical space to split between the top and bottom bars: '(0, 1)'.
+*/
+
+/*
+*   If our rectangle is an image, how much memory does its pixel buffer need? That's the number of pixels times the number
+*   of bytes per pixel. Multiplying three 'u32's together overflows quickly for big images, so 'buffer_bytes' widens each
+*   one to 'u64' before multiplying:
+*/
+
+impl Rectangle {
+    pub fn buffer_bytes(&self, bytes_per_pixel: u32) -> u64 {
+        self.width as u64 * self.height as u64 * bytes_per_pixel as u64
+    }
+}
+
+fn buffer_bytes_example() {
+    assert_eq!(Rectangle::new(1920, 1080).buffer_bytes(4), 8_294_400);
+    assert_eq!(Rectangle::new(u32::MAX, u32::MAX).buffer_bytes(1), 18_446_744_065_119_617_025);
+}
+
+/*
+*   A 1920x1080 image with 4 bytes per pixel (red, green, blue, and alpha) needs 8,294,400 bytes.
+*/
+
+/*
+*   Iterators have a 'collect' method that can build all sorts of things, and a type opts in to being collected into by
+*   implementing the 'FromIterator' trait. 'RectBounds' collects '(width, height)' pairs and remembers the largest width
+*   and the largest height it has seen, which gives the smallest rectangle that every one of the sizes fits inside:
+*/
+
+pub(crate) struct RectBounds {
+    width: u32,
+    height: u32,
+}
+
+impl RectBounds {
+    pub fn rectangle(&self) -> Rectangle {
+        Rectangle {
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl std::iter::FromIterator<(u32, u32)> for RectBounds {
+    fn from_iter<I: IntoIterator<Item = (u32, u32)>>(iter: I) -> Self {
+        let mut bounds = RectBounds {
+            width: 0,
+            height: 0,
+        };
+
+        for (width, height) in iter {
+            bounds.width = bounds.width.max(width);
+            bounds.height = bounds.height.max(height);
+        }
+
+        bounds
+    }
+}
+
+fn bounds_example() {
+    let sizes = vec![(30, 50), (10, 40), (60, 45)];
+
+    let bounds = sizes.into_iter().collect::<RectBounds>();
+    assert_eq!(bounds.rectangle(), Rectangle::new(60, 50));
+
+    let empty = Vec::new().into_iter().collect::<RectBounds>();
+    assert_eq!(empty.rectangle(), Rectangle::new(0, 0));
+}
+
+/*
+*   The '<I: IntoIterator<Item = (u32, u32)>>' part is a generic parameter, which is covered in Chapter 10. For now, just
+*   read it as "any 'iter' that produces '(u32, u32)' pairs". Collecting our three rectangles from 'main8' gives bounds of
+*   60x50, and collecting nothing at all gives a 0x0 rectangle.
+*/
+
+/*
+*   Bricks in a wall aren't stacked straight up: every other row is shifted sideways by half a brick. 'tessellation_count'
+*   counts how many cells fit in that brick layout. Without the offset it's the same as 'tile_count'. With it, every odd
+*   row loses half a cell of width at its start, which may or may not cost that row a whole cell:
+*/
+
+impl Rectangle {
+    pub fn tessellation_count(&self, cell: &Rectangle, offset_rows: bool) -> u64 {
+        if !offset_rows {
+            return self.tile_count(cell);
+        }
+
+        if cell.width == 0 || cell.height == 0 {
+            return 0;
+        }
+
+        let rows = (self.height / cell.height) as u64;
+        let per_row = (self.width / cell.width) as u64;
+        let per_offset_row = (self.width.saturating_sub(cell.width / 2) / cell.width) as u64;
+
+        // Rows 0, 2, 4, ... start flush against the edge; rows 1, 3, 5, ... are offset.
+        let offset_count = rows / 2;
+        (rows - offset_count) * per_row + offset_count * per_offset_row
+    }
+}
+
+fn tessellation_count_example() {
+    let cell = Rectangle::new(10, 10);
+
+    let wall = Rectangle::new(30, 20);
+    assert_eq!(wall.tessellation_count(&cell, false), wall.tile_count(&cell));
+    assert_eq!(wall.tile_count(&cell), 6);
+    assert_eq!(wall.tessellation_count(&cell, true), 5);
+
+    let wider_wall = Rectangle::new(35, 20);
+    assert_eq!(wider_wall.tessellation_count(&cell, true), 6);
+}
+
+/*
+*   A 30x20 wall fits 3 cells of 10x10 in each of its 2 straight rows, so 'tile_count' is 6. In the brick layout the
+*   second row starts 5 pixels in and only has room for 2 cells, so 'tessellation_count(&cell, true)' is 5. A 35-pixel-wide
+*   wall has enough slack that the offset row still fits 3.
+*/
+
+/*
+*   Given a history of a rectangle's sizes, is it getting bigger or smaller? 'area_trend' looks at every consecutive pair
+*   of areas and sums up the direction with a 'Trend' enum. It's strict: every step has to go up for 'Growing' (or down for
+*   'Shrinking'), no step can change for 'Stable', and anything else is 'Mixed'. With fewer than two rectangles there is no
+*   step to look at, so we return 'None':
+*/
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum Trend {
+    Growing,
+    Shrinking,
+    Stable,
+    Mixed,
+}
+
+impl Rectangle {
+    pub fn area_trend(history: &[Rectangle]) -> Option<Trend> {
+        if history.len() < 2 {
+            return None;
+        }
+
+        let steps: Vec<std::cmp::Ordering> = history
+            .windows(2)
+            .map(|pair| {
+                let before = pair[0].width as u64 * pair[0].height as u64;
+                let after = pair[1].width as u64 * pair[1].height as u64;
+                after.cmp(&before)
+            })
+            .collect();
+
+        let all = |ordering| steps.iter().all(|step| *step == ordering);
+
+        if all(std::cmp::Ordering::Greater) {
+            Some(Trend::Growing)
+        } else if all(std::cmp::Ordering::Less) {
+            Some(Trend::Shrinking)
+        } else if all(std::cmp::Ordering::Equal) {
+            Some(Trend::Stable)
+        } else {
+            Some(Trend::Mixed)
+        }
+    }
+}
+
+fn area_trend_example() {
+    let squares = |sides: &[u32]| -> Vec<Rectangle> {
+        sides
+            .iter()
+            .map(|&side| Rectangle::new(side, side))
+            .collect()
+    };
+
+    assert_eq!(
+        Rectangle::area_trend(&squares(&[1, 2, 3])),
+        Some(Trend::Growing)
+    );
+    assert_eq!(
+        Rectangle::area_trend(&squares(&[3, 2, 1])),
+        Some(Trend::Shrinking)
+    );
+    assert_eq!(
+        Rectangle::area_trend(&squares(&[1, 3, 2])),
+        Some(Trend::Mixed)
+    );
+    assert_eq!(Rectangle::area_trend(&squares(&[1])), None);
+
+    let same_area = [Rectangle::new(2, 2), Rectangle::new(1, 4)];
+    assert_eq!(Rectangle::area_trend(&same_area), Some(Trend::Stable));
+}
+
+/*
+*   'windows(2)' hands us overlapping pairs, so '[a, b, c]' gives '[a, b]' and then '[b, c]'. Sizes of 1x1, 2x2, 3x3 are
+*   'Growing', while 1x1, 3x3, 2x2 goes up and then down, so it's 'Mixed'.
+*/
+
+/*
+*   Our 'Rectangle' only knows its size, not where it is. To place one inside another we need a position, so we add a small
+*   'Point2' struct for a 2D point with signed coordinates (signed, because a rectangle bigger than its container has to
+*   start to the left of it or above it). The nine ways to line a rectangle up inside a container are a perfect fit for an
+*   enum, and 'align_within' returns the top-left corner where 'self' should go, with the container's top-left corner at
+*   '(0, 0)' and 'y' growing downwards like on a screen:
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Point2 {
+    pub(crate) x: i32,
+    pub(crate) y: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Alignment {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl Rectangle {
+    pub fn align_within(&self, container: &Rectangle, alignment: Alignment) -> Point2 {
+        let free_x = container.width as i64 - self.width as i64;
+        let free_y = container.height as i64 - self.height as i64;
+
+        let (x, y) = match alignment {
+            Alignment::TopLeft => (0, 0),
+            Alignment::Top => (free_x / 2, 0),
+            Alignment::TopRight => (free_x, 0),
+            Alignment::Left => (0, free_y / 2),
+            Alignment::Center => (free_x / 2, free_y / 2),
+            Alignment::Right => (free_x, free_y / 2),
+            Alignment::BottomLeft => (0, free_y),
+            Alignment::Bottom => (free_x / 2, free_y),
+            Alignment::BottomRight => (free_x, free_y),
+        };
+
+        Point2 {
+            x: x as i32,
+            y: y as i32,
+        }
+    }
+}
+
+fn align_within_example() {
+    let container = Rectangle::new(100, 100);
+    let icon = Rectangle::new(10, 10);
+
+    assert_eq!(
+        icon.align_within(&container, Alignment::Center),
+        Point2 { x: 45, y: 45 }
+    );
+    assert_eq!(
+        icon.align_within(&container, Alignment::TopLeft),
+        Point2 { x: 0, y: 0 }
+    );
+    assert_eq!(
+        icon.align_within(&container, Alignment::BottomRight),
+        Point2 { x: 90, y: 90 }
+    );
+    assert_eq!(
+        Rectangle::new(11, 10).align_within(&container, Alignment::Top),
+        Point2 { x: 44, y: 0 }
+    );
+
+    let banner = Rectangle::new(120, 10);
+    assert_eq!(
+        banner.align_within(&container, Alignment::Left),
+        Point2 { x: 0, y: 45 }
+    );
+    assert_eq!(
+        banner.align_within(&container, Alignment::Center),
+        Point2 { x: -10, y: 45 }
+    );
+}
+
+/*
+*   Centering a 10x10 rectangle in a 100x100 container leaves 90 pixels of free space each way, so it goes at
+*   'Point2 { x: 45, y: 45 }', while 'Alignment::TopLeft' always gives 'Point2 { x: 0, y: 0 }'. When the free space is odd,
+*   the extra pixel ends up on the right or bottom side.
+*/
+
+/*
+*   We can also think of a rectangle as a grid of cells that are either filled or empty, like the squares on a game board.
+*   'to_grid' turns a 'Rectangle' into a 'Grid' with every cell empty. The cells live in one 'Vec<bool>' stored row by row,
+*   so the cell at '(x, y)' is at index 'y * width + x'. Asking about a cell outside the grid gives 'false', and setting one
+*   does nothing, so callers never have to worry about an index out of bounds panic:
+*/
+
+pub(crate) struct Grid {
+    width: u32,
+    height: u32,
+    cells: Vec<bool>,
+}
+
+impl Rectangle {
+    pub fn to_grid(&self) -> Grid {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: vec![false; self.width as usize * self.height as usize],
+        }
+    }
+}
+
+impl Grid {
+    fn index(&self, x: u32, y: u32) -> Option<usize> {
+        if x < self.width && y < self.height {
+            Some(y as usize * self.width as usize + x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn set(&mut self, x: u32, y: u32) {
+        if let Some(index) = self.index(x, y) {
+            self.cells[index] = true;
+        }
+    }
+
+    pub fn get(&self, x: u32, y: u32) -> bool {
+        match self.index(x, y) {
+            Some(index) => self.cells[index],
+            None => false,
+        }
+    }
+}
+
+fn grid_example() {
+    let mut grid = Rectangle::new(3, 2).to_grid();
+    grid.set(0, 0);
+    grid.set(2, 1);
+    grid.set(3, 0); // outside the grid, so nothing happens
+
+    assert!(grid.get(0, 0));
+    assert!(grid.get(2, 1));
+    assert!(!grid.get(1, 0));
+    assert!(!grid.get(0, 1));
+    assert!(!grid.get(3, 0));
+    assert!(!grid.get(0, 2));
+    assert_eq!(grid.cells.iter().filter(|&&cell| cell).count(), 2);
+}
+
+/*
+*   After 'let mut grid = Rectangle { width: 3, height: 2 }.to_grid();' and 'grid.set(2, 1);', 'grid.get(2, 1)' is 'true',
+*   'grid.get(0, 0)' is still 'false', and 'grid.get(3, 0)' is 'false' because there is no fourth column.
+*/
+
+/*
+*   The golden spiral is drawn by cutting the largest possible square off a golden rectangle, then doing the same to the
+*   rectangle that's left over, again and again. 'golden_subdivisions' performs up to 'n' of those cuts and returns the
+*   squares in the order they were removed. It works on any rectangle, not only golden ones, and it stops early once
+*   nothing is left to cut:
+*/
+
+impl Rectangle {
+    pub fn golden_subdivisions(&self, n: usize) -> Vec<Rectangle> {
+        let mut squares = Vec::new();
+        let (mut long, mut short) = (self.width.max(self.height), self.width.min(self.height));
+
+        while squares.len() < n && short > 0 {
+            squares.push(Rectangle {
+                width: short,
+                height: short,
+            });
+            (long, short) = (short.max(long - short), short.min(long - short));
+        }
+
+        squares
+    }
+}
+
+fn golden_subdivisions_example() {
+    let squares = Rectangle::new(89, 55).golden_subdivisions(5);
+    let sides: Vec<u32> = squares.iter().map(|square| square.width).collect();
+
+    assert_eq!(sides, vec![55, 34, 21, 13, 8]);
+    assert!(squares.iter().all(|square| square.width == square.height));
+    assert!(squares
+        .windows(2)
+        .all(|pair| pair[1].area() < pair[0].area()));
+
+    assert_eq!(
+        Rectangle::new(3, 3).golden_subdivisions(5),
+        vec![Rectangle::new(3, 3)]
+    );
+    assert_eq!(Rectangle::new(0, 10).golden_subdivisions(5), vec![]);
+}
+
+/*
+*   The line '(long, short) = (..., ...)' is a destructuring assignment: it updates both variables at once from a tuple.
+*   For a 89x55 rectangle (two Fibonacci numbers, which is about as golden as whole numbers get) the first square has a side
+*   of 55, then 34, 21, 13, and so on, each one smaller than the last.
+*/
+
+/*
+*   Thumbnails need to fit in a small box without being stretched. 'thumbnail' scales the rectangle down so its longer side
+*   is 'max_dimension', scaling the shorter side by the same amount (rounded to the nearest pixel). A rectangle that already
+*   fits is returned unchanged; we never scale up:
+*/
+
+impl Rectangle {
+    pub fn thumbnail(&self, max_dimension: u32) -> Rectangle {
+        let longest = self.width.max(self.height);
+        if longest <= max_dimension {
+            return Rectangle {
+                width: self.width,
+                height: self.height,
+            };
+        }
+
+        let scale = |side: u32| {
+            ((side as u64 * max_dimension as u64 + longest as u64 / 2) / longest as u64) as u32
+        };
+
+        Rectangle {
+            width: scale(self.width),
+            height: scale(self.height),
+        }
+    }
+}
+
+fn thumbnail_example() {
+    assert_eq!(
+        Rectangle::new(4000, 3000).thumbnail(400),
+        Rectangle::new(400, 300)
+    );
+    assert_eq!(
+        Rectangle::new(3000, 4000).thumbnail(400),
+        Rectangle::new(300, 400)
+    );
+    assert_eq!(
+        Rectangle::new(100, 100).thumbnail(400),
+        Rectangle::new(100, 100)
+    );
+    assert_eq!(
+        Rectangle::new(1000, 3).thumbnail(100),
+        Rectangle::new(100, 0)
+    );
+}
+
+/*
+*   The 'scale' closure does the math in 'u64' whole numbers: multiplying before dividing keeps the precision, and adding
+*   half of 'longest' first makes the division round to the nearest pixel instead of always rounding down. A 4000x3000
+*   photo becomes a 400x300 thumbnail with a 'max_dimension' of 400, and a 100x100 icon stays 100x100.
+*/
+
+/*
+*   Games store textures at several sizes, each half the size of the one before, so that far-away objects can use a small
+*   copy. That list is called a mipmap chain. 'mipmap_chain' starts with 'self' and keeps halving both sides (rounding up,
+*   so a side never drops to zero) until it reaches 1x1. A rectangle with a zero side has no pixels to shrink, so its chain
+*   is empty:
+*/
+
+impl Rectangle {
+    pub fn mipmap_chain(&self) -> Vec<Rectangle> {
+        if self.width == 0 || self.height == 0 {
+            return Vec::new();
+        }
+
+        let (mut width, mut height) = (self.width, self.height);
+        let mut chain = vec![Rectangle { width, height }];
+
+        while width > 1 || height > 1 {
+            width = width.div_ceil(2);
+            height = height.div_ceil(2);
+            chain.push(Rectangle { width, height });
+        }
+
+        chain
+    }
+}
+
+fn mipmap_chain_example() {
+    let sides: Vec<u32> = Rectangle::new(8, 8)
+        .mipmap_chain()
+        .iter()
+        .map(|level| level.width)
+        .collect();
+    assert_eq!(sides, vec![8, 4, 2, 1]);
+
+    assert_eq!(
+        Rectangle::new(5, 3).mipmap_chain(),
+        vec![
+            Rectangle::new(5, 3),
+            Rectangle::new(3, 2),
+            Rectangle::new(2, 1),
+            Rectangle::new(1, 1)
+        ]
+    );
+    assert_eq!(
+        Rectangle::new(1, 1).mipmap_chain(),
+        vec![Rectangle::new(1, 1)]
+    );
+    assert_eq!(Rectangle::new(0, 8).mipmap_chain(), vec![]);
+}
+
+/*
+*   An 8x8 texture gives 8x8, 4x4, 2x2, and 1x1. Sides that aren't powers of two still get there: 5x3 goes to 3x2, then
+*   2x1, then 1x1.
+*/
+
+/*
+*   Picture a little robot tracing the outline of a rectangle placed with its top-left corner at '(origin_x, origin_y)'.
+*   At each corner it needs to know which way to head to reach the next one. 'perimeter_walk' returns the four corners in
+*   clockwise order, starting at the top-left, each paired with a 'Heading'. Since 'y' grows downwards on a screen, going
+*   from the top edge to the bottom edge means heading 'South'.
+*
+*   A rectangle near the edge of the 'i32' range can have corners that don't fit in an 'i32' at all: adding a width of
+*   'u32::MAX' to any origin overflows. So we do the addition in 'i64', where it always fits, and convert back with
+*   'i32::try_from', which fails instead of silently wrapping around. If either far corner is out of range there is no
+*   walk to return, so we give back 'None':
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Heading {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Rectangle {
+    pub fn perimeter_walk(&self, origin_x: i32, origin_y: i32) -> Option<Vec<(Point2, Heading)>> {
+        let right = i32::try_from(origin_x as i64 + self.width as i64).ok()?;
+        let bottom = i32::try_from(origin_y as i64 + self.height as i64).ok()?;
+
+        Some(vec![
+            (Point2 { x: origin_x, y: origin_y }, Heading::East),
+            (Point2 { x: right, y: origin_y }, Heading::South),
+            (Point2 { x: right, y: bottom }, Heading::West),
+            (Point2 { x: origin_x, y: bottom }, Heading::North),
+        ])
+    }
+}
+
+fn perimeter_walk_example() {
+    assert_eq!(
+        Rectangle::new(30, 50).perimeter_walk(0, 0),
+        Some(vec![
+            (Point2 { x: 0, y: 0 }, Heading::East),
+            (Point2 { x: 30, y: 0 }, Heading::South),
+            (Point2 { x: 30, y: 50 }, Heading::West),
+            (Point2 { x: 0, y: 50 }, Heading::North),
+        ])
+    );
+
+    let walk = Rectangle::new(10, 20).perimeter_walk(-5, 100).unwrap();
+    assert_eq!(walk[2], (Point2 { x: 5, y: 120 }, Heading::West));
+
+    assert_eq!(Rectangle::new(1, 1).perimeter_walk(0, i32::MAX), None);
+
+    let widest = Rectangle::new(u32::MAX, 1);
+    assert_eq!(widest.perimeter_walk(0, 0), None);
+    assert!(widest.perimeter_walk(i32::MIN, 0).is_some());
+}
+
+/*
+*   For a 30x50 rectangle at '(0, 0)' the robot goes East from '(0, 0)', South from '(30, 0)', West from '(30, 50)', and
+*   North from '(0, 50)', which brings it back to where it started.
+*/
+
+/*
+*   In a game, when two objects end up overlapping we push one of them out of the way. The smallest push that does the job
+*   is called the minimum translation vector. 'resolve_overlap' treats 'self' as placed at '(x1, y1)' and 'other' at
+*   '(x2, y2)', measures how far they overlap along each axis, and pushes 'self' along whichever axis needs the shorter
+*   move, away from the center of 'other'. Rectangles that only touch at an edge don't overlap, so that gives 'None':
+*/
+
+impl Rectangle {
+    pub fn resolve_overlap(
+        &self,
+        x1: i32,
+        y1: i32,
+        other: &Rectangle,
+        x2: i32,
+        y2: i32,
+    ) -> Option<(i32, i32)> {
+        let (left1, top1) = (x1 as i64, y1 as i64);
+        let (right1, bottom1) = (left1 + self.width as i64, top1 + self.height as i64);
+        let (left2, top2) = (x2 as i64, y2 as i64);
+        let (right2, bottom2) = (left2 + other.width as i64, top2 + other.height as i64);
+
+        let overlap_x = right1.min(right2) - left1.max(left2);
+        let overlap_y = bottom1.min(bottom2) - top1.max(top2);
+        if overlap_x <= 0 || overlap_y <= 0 {
+            return None;
+        }
+
+        // Comparing doubled centers avoids dividing by two.
+        let push_left = left1 + right1 < left2 + right2;
+        let push_up = top1 + bottom1 < top2 + bottom2;
+
+        if overlap_x <= overlap_y {
+            let dx = if push_left { -overlap_x } else { overlap_x };
+            Some((dx as i32, 0))
+        } else {
+            let dy = if push_up { -overlap_y } else { overlap_y };
+            Some((0, dy as i32))
+        }
+    }
+}
+
+fn resolve_overlap_example() {
+    let player = Rectangle::new(10, 10);
+    let wall = Rectangle::new(10, 10);
+
+    let (dx, dy) = player.resolve_overlap(0, 0, &wall, 7, 2).unwrap();
+    assert_eq!((dx, dy), (-3, 0));
+    assert_eq!(player.resolve_overlap(dx, dy, &wall, 7, 2), None);
+
+    let (dx, dy) = player.resolve_overlap(1, 6, &wall, 0, 0).unwrap();
+    assert_eq!((dx, dy), (0, 4));
+    assert_eq!(player.resolve_overlap(1 + dx, 6 + dy, &wall, 0, 0), None);
+
+    assert_eq!(player.resolve_overlap(10, 0, &wall, 0, 0), None);
+}
+
+/*
+*   A 10x10 rectangle at '(0, 0)' and another at '(8, 2)' overlap by 2 pixels horizontally and 8 vertically, so the
+*   cheapest fix is '(-2, 0)': moving the first one two pixels to the left, after which they only touch.
+*/
+
+/*
+*   For scattering things around, like stars in a game background, we want random points inside a rectangle. Like
+*   'Coin::random_purse' in the enums chapter, 'random_point' lives behind the optional 'rand' feature and takes the random
+*   number generator as a parameter. The rectangle is placed with its top-left corner at 'origin', and the point can land
+*   on any pixel inside it, from 'origin' itself up to one less than 'origin' plus the width and height. A rectangle with no
+*   area has no pixels to pick from, so in that case we just return 'origin':
+*/
+
+#[cfg(feature = "rand")]
+impl Rectangle {
+    pub fn random_point(&self, origin: Point2, rng: &mut impl rand::Rng) -> Point2 {
+        if self.width == 0 || self.height == 0 {
+            return origin;
+        }
+
+        let x = rng.gen_range(origin.x as i64..origin.x as i64 + self.width as i64);
+        let y = rng.gen_range(origin.y as i64..origin.y as i64 + self.height as i64);
+
+        Point2 {
+            x: x as i32,
+            y: y as i32,
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+fn random_point_example() {
+    let mut rng = rand::thread_rng();
+    let rect = Rectangle::new(3, 2);
+    let origin = Point2 { x: 10, y: 20 };
+
+    let mut seen = std::collections::HashSet::new();
+    for _ in 0..1000 {
+        let point = rect.random_point(origin, &mut rng);
+        assert!((10..13).contains(&point.x) && (20..22).contains(&point.y));
+        seen.insert((point.x, point.y));
+    }
+
+    // With 1000 tries, every one of the 6 pixels (corners included) turns up.
+    assert_eq!(seen.len(), 6);
+    assert!(seen.contains(&(10, 20)) && seen.contains(&(12, 21)));
+
+    assert_eq!(Rectangle::new(0, 5).random_point(origin, &mut rng), origin);
+}
+
+/*
+*   Every pixel is equally likely, so after enough samples from a 3x2 rectangle at '(10, 20)' we will have seen both
+*   '(10, 20)' and '(12, 21)', the top-left and bottom-right pixels, and never anything outside them.
+*/
+
+/*
+*   When logging, a rough label is often more useful than an exact size. 'size_class' sorts a rectangle into one of five
+*   buckets by its area. Each threshold is the area of a square, which makes them easy to remember:
+*
+*   "tiny"   - less than 32x32 (1,024 square pixels)
+*   "small"  - less than 256x256 (65,536)
+*   "medium" - less than 1024x1024 (1,048,576)
+*   "large"  - less than 2048x2048 (4,194,304)
+*   "huge"   - everything bigger
+*
+*   The return type is '&'static str' because every label is a string literal baked into the program, so there's nothing
+*   to allocate:
+*/
+
+impl Rectangle {
+    pub fn size_class(&self) -> &'static str {
+        match self.width as u64 * self.height as u64 {
+            0..=1_023 => "tiny",
+            1_024..=65_535 => "small",
+            65_536..=1_048_575 => "medium",
+            1_048_576..=4_194_303 => "large",
+            _ => "huge",
+        }
+    }
+}
+
+fn size_class_example() {
+    assert_eq!(Rectangle::new(1, 1).size_class(), "tiny");
+    assert_eq!(Rectangle::new(4000, 4000).size_class(), "huge");
+
+    assert_eq!(Rectangle::new(1023, 1).size_class(), "tiny");
+    assert_eq!(Rectangle::new(32, 32).size_class(), "small");
+    assert_eq!(Rectangle::new(256, 256).size_class(), "medium");
+    assert_eq!(Rectangle::new(1024, 1024).size_class(), "large");
+    assert_eq!(Rectangle::new(2048, 2048).size_class(), "huge");
+}
+
+/*
+*   A 1x1 rectangle is "tiny" and a 4000x4000 one is "huge". Right at the edges, 31x33 (1,023 square pixels) is still
+*   "tiny" while 32x32 is "small".
+*/
+
+/*
+*   'set_width' changed one field through '&mut self', but a method can just as well change several. 'scale_by' multiplies
+*   both the width and the height by 'factor' in place. We use 'saturating_mul' so that scaling a huge rectangle stops at
+*   'u32::MAX' instead of overflowing:
+*/
+
+impl Rectangle {
+    pub fn scale_by(&mut self, factor: u32) {
+        self.width = self.width.saturating_mul(factor);
+        self.height = self.height.saturating_mul(factor);
+    }
+}
+
+fn scale_example() {
+    let mut rect = Rectangle::new(2, 3);
+    rect.scale_by(4);
+
+    assert_eq!(rect.width, 8);
+    assert_eq!(rect.height, 12);
+}
+
+/*
+*   Tilt a rectangle away from you and it looks shorter than it really is. 'foreshorten' fakes that effect by scaling only
+*   the height by 'factor', where '1.0' means no tilt at all and '0.0' means the rectangle is seen exactly edge-on. Factors
+*   outside that range don't make sense for a tilt, so they are clamped into it. The width is left alone, and the new height
+*   is rounded to the nearest pixel:
+*/
+
+impl Rectangle {
+    pub fn foreshorten(&self, factor: f64) -> Rectangle {
+        let factor = factor.clamp(0.0, 1.0);
+
+        Rectangle {
+            width: self.width,
+            height: (self.height as f64 * factor).round() as u32,
+        }
+    }
+}
+
+fn foreshorten_example() {
+    let rect = Rectangle::new(30, 50);
+
+    assert_eq!(rect.foreshorten(0.5), Rectangle::new(30, 25));
+    assert_eq!(rect.foreshorten(1.0), rect);
+    assert_eq!(rect.foreshorten(0.0), Rectangle::new(30, 0));
+    assert_eq!(rect.foreshorten(2.0), rect);
+    assert_eq!(rect.foreshorten(-1.0), Rectangle::new(30, 0));
+}
+
+/*
+*   A factor of '0.5' turns a 30x50 rectangle into 30x25, and a factor of '1.0' gives back 30x50.
+*/
+
+/*
+*   This file started by storing a rectangle as a '(u32, u32)' tuple and then refactored it into a struct. We can
+*   connect the two versions with the standard 'From' trait. 'dimensions' returns the tuple form of a rectangle, 'From<(u32,
+*   u32)> for Rectangle' goes from a tuple to a 'Rectangle', and 'From<Rectangle> for (u32, u32)' goes the other way. As with
+*   'Default', these are trait implementations rather than methods in our own 'impl Rectangle' block. Implementing 'From'
+*   also gives us 'Into' for free, so 'let pair: (u32, u32) = rect.into();' works too:
+*/
+
+impl Rectangle {
+    pub fn dimensions(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+}
+
+impl From<(u32, u32)> for Rectangle {
+    fn from((width, height): (u32, u32)) -> Self {
+        Rectangle { width, height }
+    }
+}
+
+impl From<Rectangle> for (u32, u32) {
+    fn from(rect: Rectangle) -> Self {
+        rect.dimensions()
+    }
+}
+
+fn dimensions_example() {
+    let rect = Rectangle::new(30, 50);
+    assert_eq!(rect.dimensions(), (30, 50));
+    assert_eq!(Rectangle::from(rect.dimensions()), rect);
+
+    let pair: (u32, u32) = rect.into();
+    assert_eq!(pair, (30, 50));
+}
+
+/*
+*   To draw a rectangle on the screen at the right shape, we need its aspect ratio: the width divided by the height. We
+*   convert both to 'f64' first so that 30/50 gives '0.6' and not the integer '0'. A rectangle with no height would mean
+*   dividing by zero, and for a 0x0 rectangle 'f64' division would even give 'NaN' ("not a number"). Instead, 'aspect_ratio'
+*   returns 'f64::INFINITY' whenever the height is '0', which reads as "infinitely wide compared to its height":
+*/
+
+impl Rectangle {
+    pub fn aspect_ratio(&self) -> f64 {
+        if self.height == 0 {
+            return f64::INFINITY;
+        }
+
+        self.width as f64 / self.height as f64
+    }
+}
+
+fn aspect_ratio_example() {
+    assert_eq!(Rectangle::new(30, 50).aspect_ratio(), 0.6);
+    assert_eq!(Rectangle::new(30, 0).aspect_ratio(), f64::INFINITY);
+    assert_eq!(Rectangle::new(0, 0).aspect_ratio(), f64::INFINITY);
+}
+
+/*
+*   Turning a rectangle on its side swaps its width and height. 'rotated' borrows 'self' and builds a brand new
+*   'Rectangle', so the original stays usable afterwards. Compare that with 'max', which takes 'self' and uses it up:
+*/
+
+impl Rectangle {
+    pub fn rotated(&self) -> Rectangle {
+        Rectangle {
+            width: self.height,
+            height: self.width,
+        }
+    }
+}
+
+fn rotated_example() {
+    let rect = Rectangle::new(30, 50);
+
+    assert_eq!(rect.rotated(), Rectangle::new(50, 30));
+    assert_eq!(rect, Rectangle::new(30, 50)); // 'rect' is still ours to use
+}
+
+/*
+*   Our 'area' method multiplies two 'u32's, and for a big enough rectangle the result doesn't fit in a 'u32'. In a debug
+*   build that panics, and in a release build it silently wraps around to a wrong, much smaller number. 'checked_area'
+*   uses 'checked_mul' instead, which returns 'None' when the multiplication would overflow, so the caller gets to decide
+*   what to do:
+*/
+
+impl Rectangle {
+    pub fn checked_area(&self) -> Option<u32> {
+        self.width.checked_mul(self.height)
+    }
+}
+
+fn checked_area_example() {
+    assert_eq!(Rectangle::new(30, 50).checked_area(), Some(1500));
+    assert_eq!(Rectangle::new(u32::MAX, 2).checked_area(), None);
+}
+
+/*
+*   For tiling a screen, it's useful to cut a rectangle into a top half and a bottom half. 'split_horizontally' returns
+*   both halves as a tuple, each with the full width. When the height is odd the rows can't be shared evenly, so the extra
+*   row goes to the first (top) half:
+*/
+
+impl Rectangle {
+    pub fn split_horizontally(&self) -> (Rectangle, Rectangle) {
+        let bottom_height = self.height / 2;
+        let top_height = self.height - bottom_height;
+
+        (
+            Rectangle::new(self.width, top_height),
+            Rectangle::new(self.width, bottom_height),
+        )
+    }
+}
+
+fn split_example() {
+    let (top, bottom) = Rectangle::new(10, 7).split_horizontally();
+
+    assert_eq!(top, Rectangle::new(10, 4));
+    assert_eq!(bottom, Rectangle::new(10, 3));
+}
+
+/*
+*   When drawing a rectangle on screen we often want to visit every unit square inside it. 'cells' returns an iterator
+*   over each '(x, y)' pair, with x in '0..width' and y in '0..height'. Nothing is computed until the iterator is used,
+*   and it yields exactly 'width * height' items:
+*/
+
+impl Rectangle {
+    pub fn cells(&self) -> impl Iterator<Item = (u32, u32)> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+}
+
+fn cells_example() {
+    let rect = Rectangle::new(3, 2);
+
+    assert_eq!(rect.cells().count(), 6);
+    assert!(rect.cells().any(|cell| cell == (2, 1)));
+}
+
+/*
+*   The drawing scenario needs rectangles that know where they are, but the area examples above are easier to read if
+*   'Rectangle' stays a plain size. So rather than adding a position to 'Rectangle', we make a separate struct that pairs
+*   a size with the 'Point2' we already use for positions. 'translate' moves the rectangle by shifting its origin, and
+*   since it changes the rectangle in place it borrows 'self' mutably:
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct PositionedRectangle {
+    pub(crate) origin: Point2,
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+}
+
+impl PositionedRectangle {
+    pub fn translate(&mut self, dx: i32, dy: i32) {
+        self.origin.x += dx;
+        self.origin.y += dy;
+    }
+}
+
+fn translate_example() {
+    let mut rect = PositionedRectangle {
+        origin: Point2 { x: 10, y: 20 },
+        width: 30,
+        height: 50,
+    };
+
+    rect.translate(5, -25);
+
+    assert_eq!(rect.origin, Point2 { x: 15, y: -5 });
+    assert_eq!((rect.width, rect.height), (30, 50));
+}
+
+/*
+*   Now that a rectangle has a position we can ask whether a point is inside it. The left and top edges belong to the
+*   rectangle but the right and bottom edges don't, the same way '0..width' includes '0' but not 'width'. That way two
+*   rectangles placed side by side never both claim the same point. The origin is an 'i32' but the size is a 'u32', so we
+*   widen everything to 'i64' before adding, which can't overflow:
+*/
+
+impl PositionedRectangle {
+    pub fn contains_point(&self, p: Point2) -> bool {
+        let (x, y) = (p.x as i64, p.y as i64);
+        let left = self.origin.x as i64;
+        let top = self.origin.y as i64;
+
+        x >= left && x < left + self.width as i64 && y >= top && y < top + self.height as i64
+    }
+}
+
+fn contains_point_example() {
+    let rect = PositionedRectangle {
+        origin: Point2 { x: -5, y: 10 },
+        width: 10,
+        height: 4,
+    };
+
+    assert!(rect.contains_point(Point2 { x: -5, y: 10 }));
+    assert!(rect.contains_point(Point2 { x: 4, y: 13 }));
+    assert!(!rect.contains_point(Point2 { x: 5, y: 13 }));
+    assert!(!rect.contains_point(Point2 { x: 4, y: 14 }));
+    assert!(!rect.contains_point(Point2 { x: 100, y: -100 }));
+}
+
+/*
+*   'can_hold' told us whether one rectangle fits inside another; with positions we can also ask whether two rectangles
+*   overlap. Each rectangle covers a range of 'x' values and a range of 'y' values, and they overlap when both ranges
+*   overlap. We use strict inequalities, so two rectangles that only touch along an edge do not count as overlapping,
+*   which matches 'contains_point' leaving out the right and bottom edges:
+*/
+
+impl PositionedRectangle {
+    pub fn right(&self) -> i64 {
+        self.origin.x as i64 + self.width as i64
+    }
+
+    pub fn bottom(&self) -> i64 {
+        self.origin.y as i64 + self.height as i64
+    }
+
+    pub fn overlaps(&self, other: &PositionedRectangle) -> bool {
+        (self.origin.x as i64) < other.right()
+            && (other.origin.x as i64) < self.right()
+            && (self.origin.y as i64) < other.bottom()
+            && (other.origin.y as i64) < self.bottom()
+    }
+}
+
+fn overlaps_example() {
+    let at = |x, y, width, height| PositionedRectangle {
+        origin: Point2 { x, y },
+        width,
+        height,
+    };
+    let rect = at(0, 0, 10, 10);
+
+    assert!(rect.overlaps(&at(5, 5, 10, 10)));
+    assert!(!rect.overlaps(&at(20, 20, 5, 5)));
+    assert!(!rect.overlaps(&at(10, 0, 5, 5)));
+}
+
+/*
+*   When two rectangles do overlap, the overlapping region is itself a rectangle: its left edge is the larger of the two
+*   left edges, its right edge is the smaller of the two right edges, and the same goes for top and bottom. We check
+*   'overlaps' first, so rectangles that only touch along an edge give 'None' rather than a rectangle with no area. The
+*   new origin is one of the two existing origin coordinates and the new size is no bigger than either rectangle, so the
+*   casts back to 'i32' and 'u32' can't lose anything:
+*/
+
+impl PositionedRectangle {
+    pub fn intersection(&self, other: &PositionedRectangle) -> Option<PositionedRectangle> {
+        if !self.overlaps(other) {
+            return None;
+        }
+
+        let left = self.origin.x.max(other.origin.x);
+        let top = self.origin.y.max(other.origin.y);
+        let right = self.right().min(other.right());
+        let bottom = self.bottom().min(other.bottom());
+
+        Some(PositionedRectangle {
+            origin: Point2 { x: left, y: top },
+            width: (right - left as i64) as u32,
+            height: (bottom - top as i64) as u32,
+        })
+    }
+}
+
+fn intersection_example() {
+    let rect = PositionedRectangle {
+        origin: Point2 { x: 0, y: 0 },
+        width: 10,
+        height: 8,
+    };
+    let other = PositionedRectangle {
+        origin: Point2 { x: 6, y: -2 },
+        width: 10,
+        height: 5,
+    };
+
+    assert_eq!(
+        rect.intersection(&other),
+        Some(PositionedRectangle {
+            origin: Point2 { x: 6, y: 0 },
+            width: 4,
+            height: 3,
+        })
+    );
+
+    let far_away = PositionedRectangle {
+        origin: Point2 { x: 50, y: 50 },
+        width: 1,
+        height: 1,
+    };
+    assert_eq!(rect.intersection(&far_away), None);
+}
+
+/*
+*   The opposite of the intersection is the smallest rectangle that contains both: take the smaller left and top edges
+*   and the larger right and bottom edges. Unlike the intersection this always exists, so there's no 'Option'. Two
+*   rectangles at opposite ends of the 'i32' range could in theory need a width bigger than 'u32::MAX', so in that extreme
+*   case the size is capped at 'u32::MAX':
+*/
+
+impl PositionedRectangle {
+    pub fn bounding_union(&self, other: &PositionedRectangle) -> PositionedRectangle {
+        let left = self.origin.x.min(other.origin.x);
+        let top = self.origin.y.min(other.origin.y);
+        let right = self.right().max(other.right());
+        let bottom = self.bottom().max(other.bottom());
+
+        PositionedRectangle {
+            origin: Point2 { x: left, y: top },
+            width: (right - left as i64).min(u32::MAX as i64) as u32,
+            height: (bottom - top as i64).min(u32::MAX as i64) as u32,
+        }
+    }
+}
+
+fn bounding_union_example() {
+    let rect = PositionedRectangle {
+        origin: Point2 { x: 0, y: 0 },
+        width: 10,
+        height: 10,
+    };
+    let other = PositionedRectangle {
+        origin: Point2 { x: 20, y: -5 },
+        width: 5,
+        height: 5,
+    };
+
+    assert_eq!(
+        rect.bounding_union(&other),
+        PositionedRectangle {
+            origin: Point2 { x: 0, y: -5 },
+            width: 25,
+            height: 15,
+        }
+    );
+}
+
 /*
     Summary
 */
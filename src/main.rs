@@ -1,5 +1,6 @@
 pub mod example;
 pub mod enums_and_patterns;
+pub mod shapes;
 
 
 /*
@@ -36,6 +37,9 @@ struct User {
     username: String,
     email: String,
     sign_in_count: u64,
+    password_hash: Option<String>,
+    last_login: Option<u64>,
+    history: Vec<UserEvent>,
 }
 
 /*
@@ -53,6 +57,9 @@ fn _main1() {
         username: String::from("someusername123"),
         active: true,
         sign_in_count: 1,
+        password_hash: None,
+        last_login: None,
+        history: vec![UserEvent::Created],
     };
 }
 
@@ -69,6 +76,9 @@ fn _main2() {
         username: String::from("someusername123"),
         active: true,
         sign_in_count: 1,
+        password_hash: None,
+        last_login: None,
+        history: vec![UserEvent::Created],
     };
 
     user1.email = String::from("anotheremail@example.com");
@@ -90,6 +100,9 @@ fn _build_user(email: String, username: String) -> User {
         username: username,
         email: email,
         sign_in_count: 1,
+        password_hash: None,
+        last_login: None,
+        history: vec![UserEvent::Created],
     }
 }
 
@@ -116,6 +129,9 @@ fn _build_user2(email: String, username: String) -> User {
         username,
         email,
         sign_in_count: 1,
+        password_hash: None,
+        last_login: None,
+        history: vec![UserEvent::Created],
     }
 }
 
@@ -145,6 +161,9 @@ fn main3() {
         username: user1.username,
         email: String::from("another@example.com"),
         sign_in_count: user1.sign_in_count,
+        password_hash: None,
+        last_login: None,
+        history: vec![UserEvent::Created],
     };
 }
 
@@ -325,6 +344,833 @@ fn main8() {
 */
 
 
+/*
+    Giving Our Tuple Structs Some Behavior
+*/
+
+/*
+*   Tuple structs can have 'impl' blocks just like regular structs. Our 'Color' tuple struct holds red, green, and
+*   blue channels in fields '0', '1', and '2', so let's teach it a few tricks. First, an associated function that turns a
+*   CSS color keyword into a 'Color'. We lowercase the input so that "Red", "RED", and "red" all work, and return 'None'
+*   for any name we don't recognize:
+*/
+
+impl Color {
+    pub fn from_css_name(name: &str) -> Option<Color> {
+        match name.to_ascii_lowercase().as_str() {
+            "black" => Some(Color(0, 0, 0)),
+            "white" => Some(Color(255, 255, 255)),
+            "red" => Some(Color(255, 0, 0)),
+            "lime" => Some(Color(0, 255, 0)),
+            "green" => Some(Color(0, 128, 0)),
+            "blue" => Some(Color(0, 0, 255)),
+            "yellow" => Some(Color(255, 255, 0)),
+            "cyan" => Some(Color(0, 255, 255)),
+            "magenta" => Some(Color(255, 0, 255)),
+            "gray" | "grey" => Some(Color(128, 128, 128)),
+            "silver" => Some(Color(192, 192, 192)),
+            "maroon" => Some(Color(128, 0, 0)),
+            "navy" => Some(Color(0, 0, 128)),
+            "orange" => Some(Color(255, 165, 0)),
+            "purple" => Some(Color(128, 0, 128)),
+            "cornflowerblue" => Some(Color(100, 149, 237)),
+            _ => None,
+        }
+    }
+}
+
+fn from_css_name_example() {
+    let cornflower = Color::from_css_name("CornflowerBlue").unwrap();
+    assert_eq!((cornflower.0, cornflower.1, cornflower.2), (100, 149, 237));
+
+    let grey = Color::from_css_name("GREY").unwrap();
+    assert_eq!((grey.0, grey.1, grey.2), (128, 128, 128));
+
+    assert!(Color::from_css_name("not-a-color").is_none());
+}
+
+/*
+*   Only a subset of the CSS keywords is listed here, but adding more is just a matter of adding more arms.
+*   'Color::from_css_name("CornflowerBlue")' gives us 'Color(100, 149, 237)', while something like
+*   'Color::from_css_name("not-a-color")' gives us 'None'.
+*/
+
+/*
+*   Methods can take other instances of the same type as parameters. 'over' composites 'self' on top of a 'background'
+*   color, where 'alpha' is how opaque 'self' is: '1.0' means we only see 'self', '0.0' means we only see the background, and
+*   anything in between mixes the two channel by channel. Values outside that range are clamped:
+*/
+
+impl Color {
+    pub fn over(&self, alpha: f64, background: &Color) -> Color {
+        let alpha = alpha.clamp(0.0, 1.0);
+        let blend = |top: i32, bottom: i32| {
+            (top as f64 * alpha + bottom as f64 * (1.0 - alpha)).round() as i32
+        };
+
+        Color(
+            blend(self.0, background.0),
+            blend(self.1, background.1),
+            blend(self.2, background.2),
+        )
+    }
+}
+
+fn over_example() {
+    let white = Color(255, 255, 255);
+    let black = Color(0, 0, 0);
+
+    let opaque = white.over(1.0, &black);
+    assert_eq!((opaque.0, opaque.1, opaque.2), (255, 255, 255));
+
+    let transparent = white.over(0.0, &black);
+    assert_eq!((transparent.0, transparent.1, transparent.2), (0, 0, 0));
+
+    let half = white.over(0.5, &black);
+    assert_eq!((half.0, half.1, half.2), (128, 128, 128));
+}
+
+/*
+*   The 'blend' closure captures 'alpha' so we don't have to repeat the formula three times. Drawing white over black at
+*   an alpha of '0.5' gives us the midpoint, 'Color(128, 128, 128)'.
+*/
+
+/*
+*   Photo filters are just math on the channels. 'sepia' mixes the three channels with the standard sepia weights, and
+*   'adjust_brightness' multiplies every channel by 'factor'. Both can push a channel above 255 (or below 0 for a negative
+*   factor), so they clamp each channel back into range rather than producing a color that doesn't exist:
+*/
+
+impl Color {
+    pub fn sepia(&self) -> Color {
+        let (r, g, b) = (self.0 as f64, self.1 as f64, self.2 as f64);
+        let channel = |value: f64| (value.round() as i32).clamp(0, 255);
+
+        Color(
+            channel(0.393 * r + 0.769 * g + 0.189 * b),
+            channel(0.349 * r + 0.686 * g + 0.168 * b),
+            channel(0.272 * r + 0.534 * g + 0.131 * b),
+        )
+    }
+
+    pub fn adjust_brightness(&self, factor: f64) -> Color {
+        let channel = |value: i32| ((value as f64 * factor).round() as i32).clamp(0, 255);
+
+        Color(channel(self.0), channel(self.1), channel(self.2))
+    }
+}
+
+fn sepia_example() {
+    let warm = Color(100, 100, 100).sepia();
+    assert_eq!((warm.0, warm.1, warm.2), (135, 120, 94));
+    assert!(warm.0 > warm.1 && warm.1 > warm.2);
+
+    let bright = Color(128, 128, 128).adjust_brightness(2.0);
+    assert_eq!((bright.0, bright.1, bright.2), (255, 255, 255));
+
+    let dark = Color(128, 128, 128).adjust_brightness(-1.0);
+    assert_eq!((dark.0, dark.1, dark.2), (0, 0, 0));
+}
+
+/*
+*   Because the red weights add up to more than the blue weights, a gray like 'Color(100, 100, 100)' comes out warm:
+*   'Color(135, 120, 94)'. Doubling the brightness of 'Color(128, 128, 128)' saturates every channel at 255, giving white.
+*/
+
+/*
+*   How bright does a color look? Our eyes are far more sensitive to green than to blue, so 'luminance' weights the
+*   channels differently. It follows the WCAG definition of relative luminance: each channel is first converted from the
+*   sRGB curve back to linear light, and the result runs from '0.0' for black to '1.0' for white.
+*
+*   'sort_by_luminance' then orders a palette from darkest to lightest. 'f64' doesn't implement 'Ord' because of 'NaN', so
+*   we can't call 'sort_by_key'; instead we use 'sort_by' with 'f64::total_cmp', which gives every float a place in one
+*   consistent order:
+*/
+
+impl Color {
+    pub fn luminance(&self) -> f64 {
+        let linear = |channel: i32| {
+            let c = channel.clamp(0, 255) as f64 / 255.0;
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+
+        0.2126 * linear(self.0) + 0.7152 * linear(self.1) + 0.0722 * linear(self.2)
+    }
+
+    pub fn sort_by_luminance(colors: &mut [Color]) {
+        colors.sort_by(|a, b| a.luminance().total_cmp(&b.luminance()));
+    }
+}
+
+fn sort_by_luminance_example() {
+    assert_eq!(Color(0, 0, 0).luminance(), 0.0);
+    assert_eq!(Color(255, 255, 255).luminance(), 1.0);
+
+    let mut palette = [Color(255, 255, 255), Color(0, 0, 0), Color(128, 128, 128)];
+    Color::sort_by_luminance(&mut palette);
+
+    let channels: Vec<_> = palette.iter().map(|color| (color.0, color.1, color.2)).collect();
+    assert_eq!(channels, vec![(0, 0, 0), (128, 128, 128), (255, 255, 255)]);
+}
+
+/*
+*   Sorting '[white, black, Color(128, 128, 128)]' leaves us with black, then the gray, then white.
+*/
+
+/*
+*   Luminance lets us check whether text is readable. The WCAG contrast ratio between two colors is
+*   '(lighter + 0.05) / (darker + 0.05)', which runs from 1:1 for identical colors up to 21:1 for black on white. WCAG
+*   then sets three bars to clear: 4.5 for normal text at level AA, 3.0 for large text at level AA, and 7.0 for level AAA:
+*/
+
+impl Color {
+    pub fn contrast_ratio(&self, other: &Color) -> f64 {
+        let (a, b) = (self.luminance(), other.luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    pub fn passes_aa(&self, bg: &Color) -> bool {
+        self.contrast_ratio(bg) >= 4.5
+    }
+
+    pub fn passes_aa_large(&self, bg: &Color) -> bool {
+        self.contrast_ratio(bg) >= 3.0
+    }
+
+    pub fn passes_aaa(&self, bg: &Color) -> bool {
+        self.contrast_ratio(bg) >= 7.0
+    }
+}
+
+fn contrast_example() {
+    let (black, white, gray) = (Color(0, 0, 0), Color(255, 255, 255), Color(128, 128, 128));
+
+    assert_eq!(black.contrast_ratio(&white), 21.0);
+    assert!(black.passes_aa(&white) && black.passes_aa_large(&white) && black.passes_aaa(&white));
+
+    assert!((gray.contrast_ratio(&white) - 3.9).abs() < 0.05);
+    assert!(gray.passes_aa_large(&white));
+    assert!(!gray.passes_aa(&white));
+    assert!(!gray.passes_aaa(&white));
+}
+
+/*
+*   Black text on a white background passes all three. A mid gray like 'Color(128, 128, 128)' on white has a ratio of
+*   about 3.9, so it's fine for large text but not for normal text.
+*/
+
+/*
+*   Old hardware could only show a handful of shades per channel. 'quantize' imitates that by snapping each channel to the
+*   nearest of '2^bits_per_channel' evenly spaced levels, then stretching the result back out to the 0-255 range so it
+*   can still be stored in a 'Color'. Bit depths outside 1 to 8 are clamped into that range:
+*/
+
+impl Color {
+    pub fn quantize(&self, bits_per_channel: u8) -> Color {
+        let levels = (1u32 << bits_per_channel.clamp(1, 8)) - 1;
+        let channel = |value: i32| {
+            let value = value.clamp(0, 255) as u32;
+            let level = (value * levels + 127) / 255;
+            ((level * 255 + levels / 2) / levels) as i32
+        };
+
+        Color(channel(self.0), channel(self.1), channel(self.2))
+    }
+}
+
+fn quantize_example() {
+    let one_bit = Color(100, 200, 127).quantize(1);
+    assert_eq!((one_bit.0, one_bit.1, one_bit.2), (0, 255, 0));
+
+    for value in 0..=255 {
+        let same = Color(value, value, value).quantize(8);
+        assert_eq!((same.0, same.1, same.2), (value, value, value));
+    }
+
+    let two_bits = Color(0, 90, 255).quantize(2);
+    assert_eq!((two_bits.0, two_bits.1, two_bits.2), (0, 85, 255));
+}
+
+/*
+*   With 1 bit per channel there are only two levels, so every channel ends up as either 0 or 255. With 8 bits there are
+*   256 levels, one for every value a channel can already have, so the color comes back unchanged.
+*/
+
+/*
+*   Light bulbs are sold by color temperature: around 2000K is the orange glow of a candle, 6500K is daylight white, and
+*   higher temperatures turn bluish. 'from_kelvin' approximates the color of a glowing body at a given temperature using
+*   Tanner Helland's well-known curve fit, which is a handful of powers and logarithms per channel. The fit is meant for
+*   1000K to 40000K, so we clamp to that range; the interesting part for everyday lighting is 1000K to 12000K:
+*/
+
+impl Color {
+    pub fn from_kelvin(kelvin: f64) -> Color {
+        let temp = kelvin.clamp(1000.0, 40000.0) / 100.0;
+        let channel = |value: f64| (value.round() as i32).clamp(0, 255);
+
+        let red = if temp <= 66.0 {
+            255.0
+        } else {
+            329.698727446 * (temp - 60.0).powf(-0.1332047592)
+        };
+
+        let green = if temp <= 66.0 {
+            99.4708025861 * temp.ln() - 161.1195681661
+        } else {
+            288.1221695283 * (temp - 60.0).powf(-0.0755148492)
+        };
+
+        let blue = if temp >= 66.0 {
+            255.0
+        } else if temp <= 19.0 {
+            0.0
+        } else {
+            138.5177312231 * (temp - 10.0).ln() - 305.0447927307
+        };
+
+        Color(channel(red), channel(green), channel(blue))
+    }
+}
+
+fn from_kelvin_example() {
+    let close = |color: Color, expected: (i32, i32, i32)| {
+        (color.0 - expected.0).abs() <= 2
+            && (color.1 - expected.1).abs() <= 2
+            && (color.2 - expected.2).abs() <= 2
+    };
+
+    assert!(close(Color::from_kelvin(6500.0), (255, 254, 250)));
+    assert!(close(Color::from_kelvin(2000.0), (255, 137, 14)));
+
+    let candle = Color::from_kelvin(2000.0);
+    assert!(candle.0 > candle.1 && candle.1 > candle.2);
+    let sky = Color::from_kelvin(12000.0);
+    assert!(sky.2 > sky.0);
+}
+
+/*
+*   6500K comes out as 'Color(255, 254, 250)', which is practically white, while 2000K gives the warm orange
+*   'Color(255, 137, 14)'.
+*/
+
+/*
+    Adding Methods to User
+*/
+
+/*
+*   Our 'User' struct has been plain data so far. Methods are covered properly in the example program, but here's a
+*   small one to start with: 'toggle_active' flips the 'active' flag and hands back what it was before the flip, which is
+*   handy for UI code that needs to know the prior state. It takes '&mut self' because it changes the instance:
+*/
+
+impl User {
+    pub fn toggle_active(&mut self) -> bool {
+        let previous = self.active;
+        if previous {
+            self.deactivate();
+        } else {
+            self.active = true;
+        }
+        previous
+    }
+}
+
+fn toggle_active_example() {
+    let mut user = User::new(String::from("someusername123"), String::from("someone@example.com"));
+
+    assert!(user.toggle_active());
+    assert!(!user.active);
+
+    assert!(!user.toggle_active());
+    assert!(user.active);
+}
+
+/*
+*   Calling 'toggle_active' on an active user returns 'true' and leaves the user inactive; calling it again returns 'false'
+*   and makes the user active again.
+*/
+
+/*
+*   'User' also has an optional 'password_hash' field, which is 'None' until the user sets a password. Setting one can
+*   fail, so 'set_password' returns a 'Result' whose error type is a 'UserError' enum. We reject passwords shorter than
+*   8 characters, and otherwise store a hash of the password rather than the password itself.
+*
+*   WARNING: the hash used here is 64-bit FNV-1a, which is fast and simple but NOT cryptographic. It only exists to show
+*   the idea of storing something other than the plaintext; real code should use a proper password hashing function such as
+*   argon2 or bcrypt.
+*/
+
+#[derive(Debug, PartialEq)]
+enum UserError {
+    PasswordTooShort,
+    MissingHeader,
+    MalformedRow { line: usize },
+    InvalidActive { line: usize },
+    InvalidSignInCount { line: usize },
+}
+
+impl User {
+    pub fn set_password(&mut self, plain: &str) -> Result<(), UserError> {
+        if plain.chars().count() < 8 {
+            return Err(UserError::PasswordTooShort);
+        }
+
+        let mut hash: u64 = 0xcbf29ce484222325;
+        for byte in plain.bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+
+        self.password_hash = Some(format!("{:016x}", hash));
+        Ok(())
+    }
+}
+
+fn set_password_example() {
+    let mut user = User::new(String::from("someusername123"), String::from("someone@example.com"));
+
+    assert_eq!(user.set_password("short"), Err(UserError::PasswordTooShort));
+    assert_eq!(user.password_hash, None);
+
+    assert_eq!(user.set_password("correct horse"), Ok(()));
+    let hash = user.password_hash.clone().unwrap();
+    assert_eq!(hash.len(), 16);
+    assert_ne!(hash, "correct horse");
+
+    user.set_password("battery staple").unwrap();
+    assert_ne!(user.password_hash, Some(hash));
+}
+
+/*
+*   We count 'chars' rather than bytes so that a password made of multi-byte characters isn't measured as longer than it
+*   looks. 'set_password("short")' gives 'Err(UserError::PasswordTooShort)' and leaves 'password_hash' alone, while
+*   'set_password("correct horse")' stores a 16-digit hexadecimal hash that is nothing like the plaintext.
+*/
+
+/*
+*   The 'last_login' field records when the user last signed in, as a Unix timestamp in seconds, or 'None' if they never
+*   have. 'record_login' sets it and bumps 'sign_in_count' in one go, so the two can't drift apart. 'is_stale' tells us
+*   whether more than 'threshold_secs' seconds have passed since that login; a user who has never signed in counts as
+*   stale. Both methods take the current time as a parameter instead of reading the clock themselves, which keeps them easy
+*   to reason about:
+*/
+
+impl User {
+    pub fn record_login(&mut self, now: u64) {
+        self.last_login = Some(now);
+        self.sign_in();
+    }
+
+    pub fn is_stale(&self, now: u64, threshold_secs: u64) -> bool {
+        match self.last_login {
+            Some(last_login) => now.saturating_sub(last_login) > threshold_secs,
+            None => true,
+        }
+    }
+}
+
+fn is_stale_example() {
+    const DAY: u64 = 24 * 60 * 60;
+    let now = 1_700_000_000;
+
+    let mut user = User::new(String::from("someusername123"), String::from("someone@example.com"));
+    assert!(user.is_stale(now, 30 * DAY));
+
+    user.record_login(now - 365 * DAY);
+    assert_eq!(user.sign_in_count, 2);
+    assert!(user.is_stale(now, 30 * DAY));
+
+    user.record_login(now - 60);
+    assert_eq!(user.last_login, Some(now - 60));
+    assert!(!user.is_stale(now, 30 * DAY));
+}
+
+/*
+*   'saturating_sub' stops at '0' instead of overflowing, so a 'now' that is somehow earlier than the last login just means
+*   "not stale". A user who logged in a year ago is stale with a 30-day threshold, while one who logged in a minute ago is not.
+*/
+
+/*
+*   Spreadsheets are a common way to move users around in bulk. 'export_csv' writes a header row and then one row per user
+*   with the 'active', 'username', 'email', and 'sign_in_count' fields. The password hash is deliberately left out, and so
+*   is 'last_login'; imported users start without either. If a value contains a comma or a double quote, we wrap it in
+*   quotes and double any quotes inside it, which is the usual CSV convention. Values containing line breaks aren't
+*   supported, since every row has to fit on one line.
+*
+*   'import_csv' reads that format back. Every way a row can be wrong gets its own 'UserError' variant carrying the line
+*   number, so the caller can point at the exact row that needs fixing:
+*/
+
+const CSV_HEADER: &str = "active,username,email,sign_in_count";
+
+fn csv_field(value: &str) -> String {
+    if value.contains(',') || value.contains('"') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn split_csv_line(line: &str) -> Option<Vec<String>> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) if field.is_empty() => in_quotes = true,
+            (',', false) => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return None; // an opening quote was never closed
+    }
+
+    fields.push(field);
+    Some(fields)
+}
+
+impl User {
+    pub fn export_csv(users: &[User]) -> String {
+        let mut csv = String::from(CSV_HEADER);
+        csv.push('\n');
+
+        for user in users {
+            csv.push_str(&format!(
+                "{},{},{},{}\n",
+                user.active,
+                csv_field(&user.username),
+                csv_field(&user.email),
+                user.sign_in_count
+            ));
+        }
+
+        csv
+    }
+
+    pub fn import_csv(s: &str) -> Result<Vec<User>, UserError> {
+        let mut lines = s.lines().enumerate();
+
+        match lines.next() {
+            Some((_, header)) if header.trim() == CSV_HEADER => (),
+            _ => return Err(UserError::MissingHeader),
+        }
+
+        let mut users = Vec::new();
+        for (index, line) in lines {
+            let line_number = index + 1;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let fields = split_csv_line(line).ok_or(UserError::MalformedRow { line: line_number })?;
+            let [active, username, email, sign_in_count] = &fields[..] else {
+                return Err(UserError::MalformedRow { line: line_number });
+            };
+
+            let mut user = User::new(username.clone(), email.clone());
+            user.active = active
+                .parse()
+                .map_err(|_| UserError::InvalidActive { line: line_number })?;
+            user.sign_in_count = sign_in_count
+                .parse()
+                .map_err(|_| UserError::InvalidSignInCount { line: line_number })?;
+            users.push(user);
+        }
+
+        Ok(users)
+    }
+}
+
+fn csv_example() {
+    let mut ferris = User::new(String::from("ferris"), String::from("ferris@example.com"));
+    ferris.sign_in();
+    let mut quoted = User::new(
+        String::from("o\"brien, pat"),
+        String::from("pat@example.com"),
+    );
+    quoted.deactivate();
+
+    let csv = User::export_csv(&[ferris, quoted]);
+    assert_eq!(
+        csv,
+        "active,username,email,sign_in_count\n\
+         true,ferris,ferris@example.com,2\n\
+         false,\"o\"\"brien, pat\",pat@example.com,1\n"
+    );
+
+    let users = User::import_csv(&csv).unwrap();
+    assert_eq!(users.len(), 2);
+    assert!(users[0].active && !users[1].active);
+    assert_eq!(users[0].username, "ferris");
+    assert_eq!(users[1].username, "o\"brien, pat");
+    assert_eq!(users[1].email, "pat@example.com");
+    assert_eq!((users[0].sign_in_count, users[1].sign_in_count), (2, 1));
+    assert_eq!(User::export_csv(&users), csv);
+    assert!(users.iter().all(|user| user.history() == [UserEvent::Created]));
+
+    let bad_active = "active,username,email,sign_in_count\nyes,ferris,ferris@example.com,3\n";
+    assert_eq!(
+        User::import_csv(bad_active).err(),
+        Some(UserError::InvalidActive { line: 2 })
+    );
+
+    let bad_count = "active,username,email,sign_in_count\ntrue,ferris,ferris@example.com,-1\n";
+    assert_eq!(
+        User::import_csv(bad_count).err(),
+        Some(UserError::InvalidSignInCount { line: 2 })
+    );
+
+    let short_row = "active,username,email,sign_in_count\ntrue,ferris\n";
+    assert_eq!(
+        User::import_csv(short_row).err(),
+        Some(UserError::MalformedRow { line: 2 })
+    );
+
+    let unclosed = "active,username,email,sign_in_count\ntrue,\"ferris,ferris@example.com,3\n";
+    assert_eq!(
+        User::import_csv(unclosed).err(),
+        Some(UserError::MalformedRow { line: 2 })
+    );
+
+    assert_eq!(
+        User::import_csv("true,ferris,ferris@example.com,3").err(),
+        Some(UserError::MissingHeader)
+    );
+}
+
+/*
+*   'let ... else' is a handy cousin of 'if let': if the pattern doesn't match, the 'else' block has to leave the function
+*   (here with 'return'), and if it does match, the bindings stay in scope for the rest of the loop. Exporting a few users
+*   and importing the result gives back users with the same fields, while a row like 'yes,ferris,ferris@example.com,3'
+*   gives 'Err(UserError::InvalidActive { line: 2 })' because "yes" isn't a 'bool'.
+*/
+
+/*
+*   Finally, let's keep an audit trail. Every interesting thing that can happen to a user is a variant of the 'UserEvent'
+*   enum, and only 'EmailChanged' needs to carry data: the new address. The 'history' field holds the events in the order
+*   they happened, and the methods that change a user each push their event onto it. That includes 'toggle_active' from
+*   earlier: when it switches a user off it now goes through 'deactivate', so the log can't say a user is active when
+*   they aren't. Switching a user back on has no event of its own, the same as in 'diff' below. 'history' hands out a
+*   read-only slice, and it's the accessor callers should use to read the log:
+*/
+
+#[derive(Debug, Clone, PartialEq)]
+enum UserEvent {
+    Created,
+    SignedIn,
+    Deactivated,
+    EmailChanged(String),
+}
+
+impl User {
+    pub fn new(username: String, email: String) -> User {
+        User {
+            active: true,
+            username,
+            email,
+            sign_in_count: 1,
+            password_hash: None,
+            last_login: None,
+            history: vec![UserEvent::Created],
+        }
+    }
+
+    pub fn sign_in(&mut self) {
+        self.sign_in_count += 1;
+        self.history.push(UserEvent::SignedIn);
+    }
+
+    pub fn deactivate(&mut self) {
+        self.active = false;
+        self.history.push(UserEvent::Deactivated);
+    }
+
+    pub fn set_email(&mut self, email: String) {
+        self.history.push(UserEvent::EmailChanged(email.clone()));
+        self.email = email;
+    }
+
+    pub fn history(&self) -> &[UserEvent] {
+        &self.history
+    }
+}
+
+fn history_example() {
+    let mut user = User::new(String::from("ferris"), String::from("ferris@example.com"));
+    user.record_login(1_700_000_000);
+    user.set_email(String::from("new@example.com"));
+    user.deactivate();
+
+    assert_eq!(
+        user.history(),
+        [
+            UserEvent::Created,
+            UserEvent::SignedIn,
+            UserEvent::EmailChanged(String::from("new@example.com")),
+            UserEvent::Deactivated,
+        ]
+    );
+    assert_eq!(user.email, "new@example.com");
+
+    let mut toggled = User::new(String::from("crab"), String::from("crab@example.com"));
+    assert!(toggled.toggle_active());
+    assert_eq!(toggled.history(), [UserEvent::Created, UserEvent::Deactivated]);
+    assert!(!toggled.toggle_active());
+    assert!(toggled.active);
+    assert_eq!(toggled.history(), [UserEvent::Created, UserEvent::Deactivated]);
+}
+
+/*
+*   'User::new' starts out like 'build_user' from the beginning of the chapter, with 'active' set to 'true' and a
+*   'sign_in_count' of '1'. 'record_login' now goes through 'sign_in', so it shows up in the log too. Creating a user, signing
+*   in, changing the email, and deactivating leaves a history of
+*   '[Created, SignedIn, EmailChanged("new@example.com"), Deactivated]'.
+*/
+
+/*
+*   Sometimes we only have two snapshots of a user, say yesterday's backup and today's copy, and want to know what happened
+*   in between. 'diff' compares 'self' against a 'previous' snapshot and describes the changes using the same 'UserEvent'
+*   variants the log uses: one 'SignedIn' for each extra sign-in, 'Deactivated' if the user went from active to inactive,
+*   and 'EmailChanged' if the address is different. There is no event for a user being reactivated or renamed, so those
+*   changes don't show up:
+*/
+
+impl User {
+    pub fn diff(&self, previous: &User) -> Vec<UserEvent> {
+        let mut events = Vec::new();
+
+        let new_sign_ins = self.sign_in_count.saturating_sub(previous.sign_in_count);
+        for _ in 0..new_sign_ins {
+            events.push(UserEvent::SignedIn);
+        }
+
+        if previous.active && !self.active {
+            events.push(UserEvent::Deactivated);
+        }
+
+        if self.email != previous.email {
+            events.push(UserEvent::EmailChanged(self.email.clone()));
+        }
+
+        events
+    }
+}
+
+fn diff_example() {
+    let yesterday = User::new(String::from("ferris"), String::from("ferris@example.com"));
+
+    let mut today = User::new(String::from("ferris"), String::from("ferris@example.com"));
+    assert_eq!(today.diff(&yesterday), vec![]);
+
+    today.set_email(String::from("crab@example.com"));
+    assert_eq!(
+        today.diff(&yesterday),
+        vec![UserEvent::EmailChanged(String::from("crab@example.com"))]
+    );
+
+    today.sign_in();
+    today.sign_in();
+    today.deactivate();
+    assert_eq!(
+        today.diff(&yesterday),
+        vec![
+            UserEvent::SignedIn,
+            UserEvent::SignedIn,
+            UserEvent::Deactivated,
+            UserEvent::EmailChanged(String::from("crab@example.com")),
+        ]
+    );
+}
+
+/*
+*   If the only difference between two snapshots is the email, the diff is a single 'EmailChanged' with the new address,
+*   and two identical users give an empty 'Vec'.
+*/
+
+/*
+*   Before saving a user from a sign-up form, we want to tell the person everything that's wrong at once instead of one
+*   problem per attempt. 'validate' runs every check and collects a message for each one that fails, rather than returning
+*   early at the first problem. An empty 'Vec' means the user is valid. The email check is deliberately simple: one '@'
+*   with something before it and a dotted domain after it:
+*/
+
+impl User {
+    pub fn validate(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+
+        if self.username.trim().is_empty() {
+            problems.push(String::from("username must not be empty"));
+        }
+
+        let email_is_valid = match self.email.split_once('@') {
+            Some((local, domain)) => {
+                !local.is_empty()
+                    && !domain.contains('@')
+                    && domain.split('.').count() >= 2
+                    && domain.split('.').all(|part| !part.is_empty())
+            }
+            None => false,
+        };
+        if !email_is_valid {
+            problems.push(format!("'{}' is not a valid email address", self.email));
+        }
+
+        if self.active && self.sign_in_count == 0 {
+            problems.push(String::from("an active user must have signed in at least once"));
+        }
+
+        problems
+    }
+}
+
+fn validate_example() {
+    let valid = User::new(
+        String::from("someusername123"),
+        String::from("someone@example.com"),
+    );
+    assert_eq!(valid.validate(), Vec::<String>::new());
+
+    let mut invalid = User::new(String::from("   "), String::from("nope"));
+    invalid.sign_in_count = 0;
+    assert_eq!(
+        invalid.validate(),
+        vec![
+            String::from("username must not be empty"),
+            String::from("'nope' is not a valid email address"),
+            String::from("an active user must have signed in at least once"),
+        ]
+    );
+
+    for email in [
+        "@example.com",
+        "a@b@example.com",
+        "someone@example",
+        "someone@example..com",
+    ] {
+        let user = User::new(String::from("someone"), String::from(email));
+        assert_eq!(user.validate().len(), 1, "{email} should be rejected");
+    }
+}
+
+/*
+*   A user with an empty username, the email "nope", and 'active: true' with a 'sign_in_count' of '0' gets three messages
+*   back, while the user from 'build_user' with a real email address gets none.
+*/
+
+
 #[allow(clippy::no_effect)]
 // Shutting up the linter
 fn main() {
@@ -0,0 +1,901 @@
+/*
+    Shapes
+*/
+
+/*
+*   At the start of the enums chapter we said that a 'Rectangle' is one of a set of possible shapes that also includes
+*   'Circle' and 'Triangle'. Here we turn that sentence into code. The 'Rectangle' variant holds the 'Rectangle' struct
+*   from the structs chapter, which is why that struct and its fields are marked 'pub(crate)': it lets another module in
+*   the same crate, like this one, use it. A circle only needs a radius and a triangle the lengths of its three sides, so
+*   those variants carry their data in named fields, just like the 'Move' variant of the 'Message' enum.
+*
+*   Not every three lengths make a triangle: each side has to be shorter than the other two put together (the triangle
+*   inequality). The type can't stop us from writing 'Triangle { a: 1.0, b: 1.0, c: 5.0 }', so it's up to whoever
+*   creates a 'Shape::Triangle' to make sure the sides are valid.
+*/
+
+use crate::example::{Point2, Rectangle};
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum Shape {
+    Rectangle(Rectangle),
+    Circle { radius: f64 },
+    Triangle { a: f64, b: f64, c: f64 },
+}
+
+fn shapes_example() {
+    let shapes = [
+        Shape::Rectangle(Rectangle {
+            width: 30,
+            height: 50,
+        }),
+        Shape::Circle { radius: 2.5 },
+        Shape::Triangle {
+            a: 3.0,
+            b: 4.0,
+            c: 5.0,
+        },
+    ];
+
+    assert!(matches!(shapes[0], Shape::Rectangle(Rectangle { width: 30, height: 50 })));
+    assert_eq!(shapes[1], Shape::Circle { radius: 2.5 });
+    assert!(matches!(shapes[2], Shape::Triangle { .. }));
+}
+
+/*
+*   Just like structs, enums can have methods. 'area' uses a 'match' to pick the right formula for each variant, and each
+*   arm binds the variant's data to names we can use in the formula. The rectangle's 'u32' sides are converted to 'f64'
+*   before multiplying, so a huge rectangle can't overflow. For the triangle we only know the sides, so we use Heron's
+*   formula, which works out the area from half the perimeter 's'. The circle's area involves pi, so its result is never
+*   exact and we compare it against the expected value with a small tolerance instead of '==':
+*/
+
+impl Shape {
+    pub(crate) fn area(&self) -> f64 {
+        match self {
+            Shape::Rectangle(rect) => rect.width as f64 * rect.height as f64,
+            Shape::Circle { radius } => std::f64::consts::PI * radius * radius,
+            Shape::Triangle { a, b, c } => {
+                debug_assert!(is_valid_triangle(*a, *b, *c), "invalid triangle sides");
+                let s = (a + b + c) / 2.0;
+                (s * (s - a) * (s - b) * (s - c)).sqrt()
+            }
+        }
+    }
+}
+
+fn area_example() {
+    let rect = Shape::Rectangle(Rectangle {
+        width: 30,
+        height: 50,
+    });
+    let circle = Shape::Circle { radius: 2.0 };
+    let triangle = Shape::Triangle {
+        a: 3.0,
+        b: 4.0,
+        c: 5.0,
+    };
+
+    assert_eq!(rect.area(), 1500.0);
+    assert!((circle.area() - 12.566_370_614).abs() < 1e-6);
+    assert_eq!(triangle.area(), 6.0);
+}
+
+/*
+*   The perimeter follows the same pattern: one 'match' arm per variant. This time the triangle is the easy one, because
+*   we store its sides directly. Both 'area' and 'perimeter' check the triangle inequality with 'debug_assert!', which
+*   panics on invalid sides in debug builds but is compiled out of release builds, so it costs nothing in production:
+*/
+
+fn is_valid_triangle(a: f64, b: f64, c: f64) -> bool {
+    a > 0.0 && b > 0.0 && c > 0.0 && a < b + c && b < a + c && c < a + b
+}
+
+impl Shape {
+    pub(crate) fn perimeter(&self) -> f64 {
+        match self {
+            Shape::Rectangle(rect) => 2.0 * (rect.width as f64 + rect.height as f64),
+            Shape::Circle { radius } => 2.0 * std::f64::consts::PI * radius,
+            Shape::Triangle { a, b, c } => {
+                debug_assert!(is_valid_triangle(*a, *b, *c), "invalid triangle sides");
+                a + b + c
+            }
+        }
+    }
+}
+
+fn perimeter_example() {
+    let rect = Shape::Rectangle(Rectangle {
+        width: 30,
+        height: 50,
+    });
+    let circle = Shape::Circle { radius: 2.0 };
+    let triangle = Shape::Triangle {
+        a: 3.0,
+        b: 4.0,
+        c: 5.0,
+    };
+
+    assert_eq!(rect.perimeter(), 160.0);
+    assert!((circle.perimeter() - 12.566_370_614).abs() < 1e-6);
+    assert_eq!(triangle.perimeter(), 12.0);
+
+    assert!(is_valid_triangle(3.0, 4.0, 5.0));
+    assert!(!is_valid_triangle(1.0, 1.0, 5.0));
+}
+
+/*
+*   The 'Shape::Circle' variant is fine when a circle is one of several shapes, but sometimes all we have is a circle. Just
+*   like 'Rectangle' in the structs chapter, we can give it its own struct with its own methods. A circle can't have a
+*   negative radius, so instead of letting anyone build one with a struct literal, 'Circle::new' checks the radius first
+*   and returns a 'Result': 'Ok' with the circle, or 'Err' with a message explaining what was wrong. A radius of 'NaN' isn't
+*   a number at all, so it's rejected too:
+*/
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct Circle {
+    radius: f64,
+}
+
+impl Circle {
+    pub(crate) fn new(radius: f64) -> Result<Circle, String> {
+        if radius < 0.0 || radius.is_nan() {
+            return Err(format!("radius must be a non-negative number, got {radius}"));
+        }
+
+        Ok(Circle { radius })
+    }
+
+    pub(crate) fn area(&self) -> f64 {
+        std::f64::consts::PI * self.radius * self.radius
+    }
+
+    pub(crate) fn circumference(&self) -> f64 {
+        2.0 * std::f64::consts::PI * self.radius
+    }
+}
+
+fn circle_example() {
+    let unit = Circle::new(1.0).unwrap();
+
+    assert_eq!(unit.area(), std::f64::consts::PI);
+    assert_eq!(unit.circumference(), std::f64::consts::TAU);
+
+    assert_eq!(
+        Circle::new(-2.0),
+        Err(String::from("radius must be a non-negative number, got -2"))
+    );
+}
+
+/*
+*   Enum methods can return a new value of the enum, just like 'Rectangle::square' returns a new 'Rectangle'. 'scale'
+*   multiplies every length of a shape by 'factor' and gives back the resized shape, leaving the original untouched.
+*   Scaling lengths by 'factor' scales the area by 'factor * factor', so doubling a circle's radius quadruples its area.
+*
+*   The rectangle variant needs a little care, because its sides are whole 'u32' numbers while the factor is an 'f64'.
+*   We round each scaled side to the nearest whole number, so scaling a 3x5 rectangle by 1.5 gives 5x8 (4.5 and 7.5 both
+*   round up), and its area won't be exactly 2.25 times the old one. Converting with 'as' also clamps the result, so a
+*   negative factor gives a rectangle with no width or height, and a huge one stops at 'u32::MAX':
+*/
+
+impl Shape {
+    pub(crate) fn scale(&self, factor: f64) -> Shape {
+        match self {
+            Shape::Rectangle(rect) => Shape::Rectangle(Rectangle {
+                width: (rect.width as f64 * factor).round() as u32,
+                height: (rect.height as f64 * factor).round() as u32,
+            }),
+            Shape::Circle { radius } => Shape::Circle {
+                radius: radius * factor,
+            },
+            Shape::Triangle { a, b, c } => Shape::Triangle {
+                a: a * factor,
+                b: b * factor,
+                c: c * factor,
+            },
+        }
+    }
+}
+
+fn scale_example() {
+    let circle = Shape::Circle { radius: 1.5 };
+    let bigger = circle.scale(2.0);
+
+    assert!((bigger.area() - 4.0 * circle.area()).abs() < 1e-9);
+
+    let rect = Shape::Rectangle(Rectangle {
+        width: 3,
+        height: 5,
+    });
+    assert_eq!(
+        rect.scale(1.5),
+        Shape::Rectangle(Rectangle {
+            width: 5,
+            height: 8,
+        })
+    );
+}
+
+/*
+*   With 'area' in place we can find the biggest shape in a collection. An empty slice has no biggest shape, so we return
+*   an 'Option'. The iterator method 'max_by' needs to know how two areas compare, but 'f64' doesn't implement 'Ord'
+*   because of 'NaN', which isn't bigger, smaller, or equal to anything. A 'NaN' area can really happen, for example from
+*   a triangle with invalid sides in a release build, so we treat it as smaller than every real area. That way a broken
+*   shape never wins, and 'total_cmp' gives us a proper ordering for everything else:
+*/
+
+pub(crate) fn largest_by_area(shapes: &[Shape]) -> Option<&Shape> {
+    let area = |shape: &Shape| {
+        let area = shape.area();
+        if area.is_nan() { f64::NEG_INFINITY } else { area }
+    };
+
+    shapes.iter().max_by(|a, b| area(a).total_cmp(&area(b)))
+}
+
+fn largest_by_area_example() {
+    let shapes = [
+        Shape::Rectangle(Rectangle {
+            width: 3,
+            height: 4,
+        }),
+        Shape::Circle { radius: 2.0 },
+        Shape::Triangle {
+            a: 3.0,
+            b: 4.0,
+            c: 5.0,
+        },
+    ];
+
+    assert_eq!(largest_by_area(&shapes), Some(&Shape::Circle { radius: 2.0 }));
+    assert_eq!(largest_by_area(&[]), None);
+}
+
+/*
+*   In the structs chapter we saw that Rust won't guess how to show a struct to a user, so '{}' only works once we
+*   implement 'Display' ourselves. '{:?}' with the derived 'Debug' is great while debugging, but for output a user will
+*   read we want something shorter, like 'Circle(r=2.50, area=19.63)'. The '{:.2}' format spec rounds a float to two
+*   decimal places. The rectangle's sides are whole numbers, so we print them without decimals:
+*/
+
+impl std::fmt::Display for Shape {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Shape::Rectangle(rect) => write!(
+                f,
+                "Rectangle({}x{}, area={:.2})",
+                rect.width,
+                rect.height,
+                self.area()
+            ),
+            Shape::Circle { radius } => write!(f, "Circle(r={:.2}, area={:.2})", radius, self.area()),
+            Shape::Triangle { a, b, c } => write!(
+                f,
+                "Triangle(a={:.2}, b={:.2}, c={:.2}, area={:.2})",
+                a,
+                b,
+                c,
+                self.area()
+            ),
+        }
+    }
+}
+
+fn display_example() {
+    let rect = Shape::Rectangle(Rectangle {
+        width: 30,
+        height: 50,
+    });
+    let circle = Shape::Circle { radius: 2.5 };
+    let triangle = Shape::Triangle {
+        a: 3.0,
+        b: 4.0,
+        c: 5.0,
+    };
+
+    assert_eq!(rect.to_string(), "Rectangle(30x50, area=1500.00)");
+    assert_eq!(circle.to_string(), "Circle(r=2.50, area=19.63)");
+    assert_eq!(triangle.to_string(), "Triangle(a=3.00, b=4.00, c=5.00, area=6.00)");
+}
+
+/*
+*   Adding up the area of every shape in a slice is a job for 'fold': we start from '0.0' and add each shape's area to the
+*   running total. An empty slice never calls the closure, so its total area is just the starting value, '0.0':
+*/
+
+pub(crate) fn total_area(shapes: &[Shape]) -> f64 {
+    shapes.iter().fold(0.0, |total, shape| total + shape.area())
+}
+
+fn total_area_example() {
+    assert_eq!(total_area(&[]), 0.0);
+
+    let shapes = [
+        Shape::Rectangle(Rectangle {
+            width: 3,
+            height: 4,
+        }),
+        Shape::Circle { radius: 1.0 },
+        Shape::Triangle {
+            a: 3.0,
+            b: 4.0,
+            c: 5.0,
+        },
+    ];
+    assert!((total_area(&shapes) - (12.0 + std::f64::consts::PI + 6.0)).abs() < 1e-9);
+}
+
+/*
+*   How round is a shape? A common measure is its compactness, '4π·area / perimeter²'. Of all the shapes with a given
+*   perimeter, the circle encloses the most area, and plugging its formulas in gives exactly '1.0'. Every other shape
+*   scores less, and the more stretched out it is, the closer it gets to '0.0'. A shape with no perimeter at all has no
+*   meaningful compactness, so we return '0.0' for it instead of dividing by zero:
+*/
+
+impl Shape {
+    pub(crate) fn compactness(&self) -> f64 {
+        let perimeter = self.perimeter();
+        if perimeter == 0.0 {
+            return 0.0;
+        }
+
+        4.0 * std::f64::consts::PI * self.area() / (perimeter * perimeter)
+    }
+}
+
+fn compactness_example() {
+    let circle = Shape::Circle { radius: 3.0 };
+    assert!((circle.compactness() - 1.0).abs() < 1e-9);
+
+    let square = Shape::Rectangle(Rectangle {
+        width: 10,
+        height: 10,
+    });
+    assert!((square.compactness() - std::f64::consts::FRAC_PI_4).abs() < 1e-9);
+
+    let sliver = Shape::Rectangle(Rectangle {
+        width: 1000,
+        height: 1,
+    });
+    assert!(sliver.compactness() < 0.01);
+}
+
+/*
+*   The derived 'PartialEq' compares floats with '==', which is often too strict: '0.1 + 0.2' isn't exactly '0.3'.
+*   'approx_eq' is a looser comparison that allows every length to be off by up to 'tolerance'. Only shapes of the same
+*   kind can be close to each other, so the last arm of the 'match' says that a circle is never approximately a rectangle,
+*   however similar their areas might be. Matching on a tuple of both shapes lets us handle every pair of variants at once:
+*/
+
+impl Shape {
+    pub(crate) fn approx_eq(&self, other: &Shape, tolerance: f64) -> bool {
+        let close = |x: f64, y: f64| (x - y).abs() <= tolerance;
+
+        match (self, other) {
+            (Shape::Rectangle(r1), Shape::Rectangle(r2)) => {
+                close(r1.width as f64, r2.width as f64) && close(r1.height as f64, r2.height as f64)
+            }
+            (Shape::Circle { radius: r1 }, Shape::Circle { radius: r2 }) => close(*r1, *r2),
+            (
+                Shape::Triangle { a: a1, b: b1, c: c1 },
+                Shape::Triangle { a: a2, b: b2, c: c2 },
+            ) => close(*a1, *a2) && close(*b1, *b2) && close(*c1, *c2),
+            _ => false,
+        }
+    }
+}
+
+fn approx_eq_example() {
+    let circle = Shape::Circle { radius: 0.1 + 0.2 };
+    assert_ne!(circle, Shape::Circle { radius: 0.3 });
+    assert!(circle.approx_eq(&Shape::Circle { radius: 0.3 }, 1e-9));
+    assert!(!circle.approx_eq(&Shape::Circle { radius: 0.4 }, 1e-9));
+
+    let square = Shape::Rectangle(Rectangle {
+        width: 1,
+        height: 1,
+    });
+    assert!(!square.approx_eq(&Shape::Circle { radius: 0.5 }, 100.0));
+    assert!(!Shape::Circle { radius: 0.5 }.approx_eq(&square, 100.0));
+}
+
+/*
+*   'Display' is meant for people. To save a shape in a file or send it somewhere, we want a compact text form that a
+*   program can read back, like "rect:30x50", "circle:2.5", or "triangle:3,4,5". 'to_descriptor' writes that form and
+*   'from_descriptor' parses it. Printing an 'f64' with '{}' gives the shortest text that parses back to exactly the same
+*   number, so descriptors round-trip without losing precision.
+*
+*   The rectangle part after the colon is the same "30x50" text that 'Rectangle::try_from' already knows how to read, so
+*   we reuse it instead of writing a second parser. We also refuse to build shapes that can't exist, such as a negative
+*   radius or three sides that break the triangle inequality:
+*/
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum ParseShapeError {
+    MissingKind,
+    UnknownKind(String),
+    InvalidValues(String),
+}
+
+impl Shape {
+    pub(crate) fn to_descriptor(&self) -> String {
+        match self {
+            Shape::Rectangle(rect) => format!("rect:{}x{}", rect.width, rect.height),
+            Shape::Circle { radius } => format!("circle:{}", radius),
+            Shape::Triangle { a, b, c } => format!("triangle:{},{},{}", a, b, c),
+        }
+    }
+
+    pub(crate) fn from_descriptor(s: &str) -> Result<Shape, ParseShapeError> {
+        let (kind, values) = s
+            .trim()
+            .split_once(':')
+            .ok_or(ParseShapeError::MissingKind)?;
+        let invalid = || ParseShapeError::InvalidValues(values.to_string());
+
+        match kind {
+            "rect" => Rectangle::try_from(values)
+                .map(Shape::Rectangle)
+                .map_err(|_| invalid()),
+            "circle" => match values.trim().parse::<f64>() {
+                Ok(radius) if radius >= 0.0 => Ok(Shape::Circle { radius }),
+                _ => Err(invalid()),
+            },
+            "triangle" => {
+                let sides = values
+                    .split(',')
+                    .map(|side| side.trim().parse::<f64>())
+                    .collect::<Result<Vec<f64>, _>>()
+                    .map_err(|_| invalid())?;
+
+                match sides[..] {
+                    [a, b, c] if is_valid_triangle(a, b, c) => Ok(Shape::Triangle { a, b, c }),
+                    _ => Err(invalid()),
+                }
+            }
+            _ => Err(ParseShapeError::UnknownKind(kind.to_string())),
+        }
+    }
+}
+
+fn descriptor_example() {
+    let shapes = [
+        Shape::Rectangle(Rectangle {
+            width: 30,
+            height: 50,
+        }),
+        Shape::Circle { radius: 2.5 },
+        Shape::Triangle {
+            a: 3.0,
+            b: 4.0,
+            c: 5.0,
+        },
+    ];
+
+    assert_eq!(shapes[0].to_descriptor(), "rect:30x50");
+    assert_eq!(shapes[1].to_descriptor(), "circle:2.5");
+    assert_eq!(shapes[2].to_descriptor(), "triangle:3,4,5");
+
+    for shape in &shapes {
+        assert_eq!(
+            Shape::from_descriptor(&shape.to_descriptor()).as_ref(),
+            Ok(shape)
+        );
+    }
+
+    let third = Shape::Circle { radius: 1.0 / 3.0 };
+    assert_eq!(Shape::from_descriptor(&third.to_descriptor()), Ok(third));
+
+    assert_eq!(
+        Shape::from_descriptor("circle"),
+        Err(ParseShapeError::MissingKind)
+    );
+    assert_eq!(
+        Shape::from_descriptor("hexagon:6"),
+        Err(ParseShapeError::UnknownKind(String::from("hexagon")))
+    );
+    assert_eq!(
+        Shape::from_descriptor("circle:-1"),
+        Err(ParseShapeError::InvalidValues(String::from("-1")))
+    );
+    assert_eq!(
+        Shape::from_descriptor("triangle:1,1,5"),
+        Err(ParseShapeError::InvalidValues(String::from("1,1,5")))
+    );
+    assert!(Shape::from_descriptor("rect:30by50").is_err());
+}
+
+/*
+*   Push a flat shape straight up and it sweeps out a solid, called a prism: a rectangle becomes a box, a circle a
+*   cylinder, and a triangle a wedge. Whatever the shape, the volume of its prism is the area of the base times the depth,
+*   so 'extruded_volume' doesn't need a 'match' at all. It just calls 'area', which already handles every variant:
+*/
+
+impl Shape {
+    pub(crate) fn extruded_volume(&self, depth: f64) -> f64 {
+        self.area() * depth
+    }
+}
+
+fn extruded_volume_example() {
+    let unit_square = Shape::Rectangle(Rectangle {
+        width: 1,
+        height: 1,
+    });
+    assert_eq!(unit_square.extruded_volume(5.0), 5.0);
+
+    let can = Shape::Circle { radius: 2.0 };
+    let cylinder = std::f64::consts::PI * 2.0 * 2.0 * 10.0;
+    assert!((can.extruded_volume(10.0) - cylinder).abs() < 1e-9);
+
+    let wedge = Shape::Triangle {
+        a: 3.0,
+        b: 4.0,
+        c: 5.0,
+    };
+    assert_eq!(wedge.extruded_volume(2.0), 12.0);
+}
+
+/*
+*   To draw a shape's outline with dots, or to check it against something else point by point, we need points along its
+*   boundary. 'sample_boundary' returns 'n' of them, spaced evenly by distance along the edge. So far our shapes only have
+*   a size and no position, so we place each one at the origin: a rectangle gets its top-left corner there, and a circle its
+*   center. The points are the 'Point2' struct from the structs chapter, whose fields are 'pub(crate)' for the same
+*   reason as the fields of 'Rectangle'. It has whole-number coordinates, so every point is rounded to the nearest pixel.
+*
+*   A circle is the easy case: we step the angle around by a full turn divided by 'n'. Rectangles and triangles are both
+*   polygons, so they share one helper that walks the edges and drops a point every 'perimeter / n' units. The triangle
+*   only knows its side lengths, so we first work out where its corners are: 'a' runs along the x axis from the origin,
+*   'b' runs from the origin to the third corner, and 'c' closes the triangle. The cosine rule tells us how far along the
+*   x axis that third corner is, and Pythagoras gives us its height:
+*/
+
+fn triangle_vertices(a: f64, b: f64, c: f64) -> [(f64, f64); 3] {
+    let x = (a * a + b * b - c * c) / (2.0 * a);
+    let y = (b * b - x * x).max(0.0).sqrt();
+    [(0.0, 0.0), (a, 0.0), (x, y)]
+}
+
+fn to_point(x: f64, y: f64) -> Point2 {
+    Point2 {
+        x: x.round() as i32,
+        y: y.round() as i32,
+    }
+}
+
+fn polygon_boundary(vertices: &[(f64, f64)], n: usize) -> Vec<Point2> {
+    let edges: Vec<_> = vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(&start, &end)| (start, end, (end.0 - start.0).hypot(end.1 - start.1)))
+        .collect();
+    let perimeter: f64 = edges.iter().map(|edge| edge.2).sum();
+
+    (0..n)
+        .map(|i| {
+            let mut distance = perimeter * i as f64 / n as f64;
+            for &(start, end, length) in &edges {
+                if distance < length {
+                    let t = distance / length;
+                    return to_point(
+                        start.0 + t * (end.0 - start.0),
+                        start.1 + t * (end.1 - start.1),
+                    );
+                }
+                distance -= length;
+            }
+            to_point(vertices[0].0, vertices[0].1)
+        })
+        .collect()
+}
+
+impl Shape {
+    pub(crate) fn sample_boundary(&self, n: usize) -> Vec<Point2> {
+        match self {
+            Shape::Rectangle(rect) => {
+                let (width, height) = (rect.width as f64, rect.height as f64);
+                polygon_boundary(
+                    &[(0.0, 0.0), (width, 0.0), (width, height), (0.0, height)],
+                    n,
+                )
+            }
+            Shape::Circle { radius } => (0..n)
+                .map(|i| {
+                    let angle = std::f64::consts::TAU * i as f64 / n as f64;
+                    to_point(radius * angle.cos(), radius * angle.sin())
+                })
+                .collect(),
+            Shape::Triangle { a, b, c } => polygon_boundary(&triangle_vertices(*a, *b, *c), n),
+        }
+    }
+}
+
+fn sample_boundary_example() {
+    let square = Shape::Rectangle(Rectangle {
+        width: 10,
+        height: 10,
+    });
+    let circle = Shape::Circle { radius: 10.0 };
+    let triangle = Shape::Triangle {
+        a: 3.0,
+        b: 4.0,
+        c: 5.0,
+    };
+
+    for shape in [&square, &circle, &triangle] {
+        assert_eq!(shape.sample_boundary(12).len(), 12);
+        assert!(shape.sample_boundary(0).is_empty());
+    }
+
+    assert_eq!(
+        square.sample_boundary(4),
+        vec![
+            Point2 { x: 0, y: 0 },
+            Point2 { x: 10, y: 0 },
+            Point2 { x: 10, y: 10 },
+            Point2 { x: 0, y: 10 },
+        ]
+    );
+
+    for point in circle.sample_boundary(36) {
+        let distance = (point.x as f64).hypot(point.y as f64);
+        assert!((distance - 10.0).abs() < 1.0);
+    }
+
+    assert_eq!(
+        triangle_vertices(3.0, 4.0, 5.0),
+        [(0.0, 0.0), (3.0, 0.0), (0.0, 4.0)]
+    );
+    assert_eq!(triangle.sample_boundary(1), vec![Point2 { x: 0, y: 0 }]);
+}
+
+/*
+*   Sometimes all we care about is how much room a shape takes up, not what it looks like. 'equivalent_square' answers
+*   "what square has about the same area?". The side of that square is the square root of the area, rounded to the nearest
+*   whole number because our 'Rectangle' has whole-number sides, so the square's area is only close to the original. A
+*   circle with a radius of 2 has an area of about 12.57, and the nearest whole side is 4, for a 4x4 square:
+*/
+
+impl Shape {
+    pub(crate) fn equivalent_square(&self) -> Rectangle {
+        let side = self.area().sqrt().round() as u32;
+        Rectangle {
+            width: side,
+            height: side,
+        }
+    }
+}
+
+fn equivalent_square_example() {
+    let circle = Shape::Circle { radius: 2.0 };
+    assert_eq!(circle.equivalent_square(), Rectangle::new(4, 4));
+
+    let rect = Shape::Rectangle(Rectangle {
+        width: 3,
+        height: 4,
+    });
+    assert_eq!(rect.equivalent_square(), Rectangle::new(3, 3));
+
+    let square = Shape::Rectangle(Rectangle {
+        width: 7,
+        height: 7,
+    });
+    assert_eq!(square.equivalent_square(), Rectangle::new(7, 7));
+}
+
+/*
+*   The centroid is a shape's balance point: if you cut the shape out of cardboard, it would balance on a pin placed there.
+*   'centroid' takes the same 'origin' convention as 'sample_boundary', so a rectangle's origin is its top-left corner and a
+*   circle's is its center. For those two the balance point is simply the middle. For a triangle it's the average of its
+*   three corners, which we already know how to find with 'triangle_vertices'. As before, the result is rounded to whole
+*   pixels:
+*/
+
+impl Shape {
+    pub(crate) fn centroid(&self, origin: Point2) -> Point2 {
+        let (x, y) = (origin.x as f64, origin.y as f64);
+
+        match self {
+            Shape::Rectangle(rect) => {
+                to_point(x + rect.width as f64 / 2.0, y + rect.height as f64 / 2.0)
+            }
+            Shape::Circle { .. } => origin,
+            Shape::Triangle { a, b, c } => {
+                let [p, q, r] = triangle_vertices(*a, *b, *c);
+                to_point(x + (p.0 + q.0 + r.0) / 3.0, y + (p.1 + q.1 + r.1) / 3.0)
+            }
+        }
+    }
+}
+
+fn centroid_example() {
+    let rect = Shape::Rectangle(Rectangle {
+        width: 30,
+        height: 50,
+    });
+    assert_eq!(
+        rect.centroid(Point2 { x: 0, y: 0 }),
+        Point2 { x: 15, y: 25 }
+    );
+    assert_eq!(
+        rect.centroid(Point2 { x: 10, y: -10 }),
+        Point2 { x: 25, y: 15 }
+    );
+
+    let circle = Shape::Circle { radius: 4.0 };
+    assert_eq!(
+        circle.centroid(Point2 { x: 7, y: 3 }),
+        Point2 { x: 7, y: 3 }
+    );
+
+    // A right triangle with legs of 9 and 12: its corners are (0, 0), (9, 0), and (0, 12).
+    let triangle = Shape::Triangle {
+        a: 9.0,
+        b: 12.0,
+        c: 15.0,
+    };
+    assert_eq!(
+        triangle.centroid(Point2 { x: 0, y: 0 }),
+        Point2 { x: 3, y: 4 }
+    );
+    assert_eq!(
+        triangle.centroid(Point2 { x: 1, y: 1 }),
+        Point2 { x: 4, y: 5 }
+    );
+}
+
+/*
+*   A shape is convex if the straight line between any two points inside it stays inside it. Rectangles and circles always
+*   are, and so is every real triangle. The only way a triangle can fail is when it's degenerate: its three corners sit on
+*   one line, like sides of 1, 2, and 3, where the two short sides exactly add up to the long one. That flat "triangle"
+*   has no inside at all, so we don't call it convex. 'is_valid_triangle' already rejects those sides, because it demands
+*   that each side be strictly shorter than the other two put together:
+*/
+
+impl Shape {
+    pub(crate) fn is_convex(&self) -> bool {
+        match self {
+            Shape::Rectangle(_) | Shape::Circle { .. } => true,
+            Shape::Triangle { a, b, c } => is_valid_triangle(*a, *b, *c),
+        }
+    }
+}
+
+fn is_convex_example() {
+    let rect = Shape::Rectangle(Rectangle {
+        width: 30,
+        height: 50,
+    });
+    let circle = Shape::Circle { radius: 2.5 };
+    let triangle = Shape::Triangle {
+        a: 3.0,
+        b: 4.0,
+        c: 5.0,
+    };
+    assert!(rect.is_convex() && circle.is_convex() && triangle.is_convex());
+
+    let flat = Shape::Triangle {
+        a: 1.0,
+        b: 2.0,
+        c: 3.0,
+    };
+    assert!(!flat.is_convex());
+}
+
+/*
+*   How much ground do several overlapping shapes cover together? Adding up their areas counts the overlaps twice, and
+*   working out the exact overlap between, say, a circle and a triangle is hard. A Monte Carlo estimate sidesteps all of
+*   that: throw random points into a box that surrounds every shape, count how many land inside at least one of them, and
+*   scale the box's area by that fraction. The more 'samples' we throw, the closer the estimate gets to the real area.
+*
+*   Each shape is paired with the 'Point2' it sits at, using the same convention as 'centroid'. To find the surrounding box
+*   and to test whether a point is inside, we add two small helpers. The triangle test checks which side of each edge the
+*   point is on: a point is inside when it isn't to the left of one edge and to the right of another. Like 'random_purse',
+*   this needs the 'rand' crate, so it only exists when the 'rand' feature is turned on:
+*/
+
+#[cfg(feature = "rand")]
+impl Shape {
+    fn bounding_box(&self, origin: Point2) -> (f64, f64, f64, f64) {
+        let (x, y) = (origin.x as f64, origin.y as f64);
+
+        match self {
+            Shape::Rectangle(rect) => (x, y, x + rect.width as f64, y + rect.height as f64),
+            Shape::Circle { radius } => (x - radius, y - radius, x + radius, y + radius),
+            Shape::Triangle { a, b, c } => {
+                let [_, (bx, _), (cx, cy)] = triangle_vertices(*a, *b, *c);
+                (x + cx.min(0.0), y, x + bx.max(cx), y + cy)
+            }
+        }
+    }
+
+    fn contains(&self, origin: Point2, px: f64, py: f64) -> bool {
+        let (x, y) = (origin.x as f64, origin.y as f64);
+
+        match self {
+            Shape::Rectangle(rect) => {
+                px >= x && py >= y && px <= x + rect.width as f64 && py <= y + rect.height as f64
+            }
+            Shape::Circle { radius } => (px - x).hypot(py - y) <= *radius,
+            Shape::Triangle { a, b, c } => {
+                let [p, q, r] = triangle_vertices(*a, *b, *c).map(|(vx, vy)| (x + vx, y + vy));
+                let side = |(ax, ay): (f64, f64), (bx, by): (f64, f64)| {
+                    (bx - ax) * (py - ay) - (by - ay) * (px - ax)
+                };
+
+                let sides = [side(p, q), side(q, r), side(r, p)];
+                !(sides.iter().any(|&s| s < 0.0) && sides.iter().any(|&s| s > 0.0))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+pub(crate) fn union_area_estimate(
+    shapes: &[(Point2, Shape)],
+    samples: usize,
+    rng: &mut impl rand::Rng,
+) -> f64 {
+    let (mut min_x, mut min_y) = (f64::INFINITY, f64::INFINITY);
+    let (mut max_x, mut max_y) = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+    for (origin, shape) in shapes {
+        let (left, top, right, bottom) = shape.bounding_box(*origin);
+        min_x = min_x.min(left);
+        min_y = min_y.min(top);
+        max_x = max_x.max(right);
+        max_y = max_y.max(bottom);
+    }
+
+    // No shapes, no samples, or nothing but zero-sized shapes: there's no area to find.
+    if samples == 0 || !(max_x > min_x && max_y > min_y) {
+        return 0.0;
+    }
+
+    let hits = (0..samples)
+        .filter(|_| {
+            let (px, py) = (rng.gen_range(min_x..max_x), rng.gen_range(min_y..max_y));
+            shapes
+                .iter()
+                .any(|(origin, shape)| shape.contains(*origin, px, py))
+        })
+        .count();
+
+    (max_x - min_x) * (max_y - min_y) * hits as f64 / samples as f64
+}
+
+#[cfg(feature = "rand")]
+fn union_area_estimate_example() {
+    let mut rng = rand::thread_rng();
+    let square = || {
+        Shape::Rectangle(Rectangle {
+            width: 10,
+            height: 10,
+        })
+    };
+
+    // Two 10x10 squares overlapping in a 5x5 corner cover 100 + 100 - 25 = 175.
+    let overlapping = [
+        (Point2 { x: 0, y: 0 }, square()),
+        (Point2 { x: 5, y: 5 }, square()),
+    ];
+    assert!((union_area_estimate(&overlapping, 100_000, &mut rng) - 175.0).abs() < 3.0);
+
+    let unit_circle = [(Point2 { x: 0, y: 0 }, Shape::Circle { radius: 1.0 })];
+    let estimate = union_area_estimate(&unit_circle, 100_000, &mut rng);
+    assert!((estimate - std::f64::consts::PI).abs() < 0.05);
+
+    let triangle = Shape::Triangle {
+        a: 9.0,
+        b: 12.0,
+        c: 15.0,
+    };
+    assert!(triangle.contains(Point2 { x: 0, y: 0 }, 3.0, 4.0));
+    assert!(!triangle.contains(Point2 { x: 0, y: 0 }, 8.0, 10.0));
+    assert_eq!(
+        triangle.bounding_box(Point2 { x: 1, y: 2 }),
+        (1.0, 2.0, 10.0, 14.0)
+    );
+
+    assert_eq!(union_area_estimate(&[], 100, &mut rng), 0.0);
+    assert_eq!(union_area_estimate(&overlapping, 0, &mut rng), 0.0);
+}